@@ -0,0 +1,131 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Interoperability with `anyhow`.
+//!
+//! This module is available with the `anyhow` feature. `CompositeError` and `ConstraintError` are
+//! `Send + Sync + 'static` errors, so `?` already converts them into an `anyhow::Error` through
+//! `anyhow`'s blanket `From` implementation, without flattening them into a string. The helpers
+//! here recover the individual failures afterwards and attach the field that failed, much like
+//! catching a Kotlin exception and reading its `cause`.
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! # use rustrict::anyhow_support::*;
+//! fn register(roles: Vec<&str>) -> anyhow::Result<()> {
+//!     let scope = RustrictScope::new();
+//!     scope.validate_string("Roles must not be empty", |s| s.must(roles, NotBeEmpty));
+//!     scope.into_result()?;
+//!     Ok(())
+//! }
+//!
+//! let error = register(vec![]).unwrap_err();
+//! assert_eq!(error.constraint_failures().len(), 1);
+//! ```
+use crate::errors::composite_error::CompositeError;
+use crate::errors::constraint_error::ConstraintError;
+use std::fmt::Display;
+
+/// Extends `ConstraintError` with conversions into `anyhow::Error`.
+pub trait ConstraintErrorExt {
+    /// Converts the error into an `anyhow::Error` whose context names the field that failed.
+    ///
+    /// The constraint error is kept as the cause, so `{:#}` prints both and
+    /// `AnyhowFailures::constraint_failures` still finds it.
+    ///
+    /// # Parameters:
+    /// - `field`: The path of the field that failed, e.g. `user.email`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # use rustrict::anyhow_support::*;
+    /// let error = ConstraintError::new(|| "Email must not be empty".to_string());
+    ///
+    /// let error = error.context_field("user.email");
+    /// assert_eq!(format!("{:#}", error), "invalid field user.email: Email must not be empty");
+    /// ```
+    fn context_field(self, field: impl Display) -> anyhow::Error;
+}
+
+impl ConstraintErrorExt for ConstraintError {
+    fn context_field(self, field: impl Display) -> anyhow::Error {
+        anyhow::Error::new(self).context(format!("invalid field {}", field))
+    }
+}
+
+/// Extends `anyhow::Error` with access to the constraint failures it wraps.
+pub trait AnyhowFailures {
+    /// Returns the constraint errors found in the chain of causes of this error.
+    ///
+    /// A `CompositeError` contributes each of its errors, in order, so the failures of a scope
+    /// survive being bubbled up as an `anyhow::Result`.
+    fn constraint_failures(&self) -> Vec<ConstraintError>;
+}
+
+impl AnyhowFailures for anyhow::Error {
+    fn constraint_failures(&self) -> Vec<ConstraintError> {
+        let mut failures = Vec::new();
+        for cause in self.chain() {
+            if let Some(composite) = cause.downcast_ref::<CompositeError>() {
+                failures.extend(
+                    composite
+                        .errors()
+                        .iter()
+                        .filter_map(|error| error.downcast_ref::<ConstraintError>())
+                        .cloned(),
+                );
+            } else if let Some(error) = cause.downcast_ref::<ConstraintError>() {
+                failures.push(error.clone());
+            }
+        }
+        failures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::have_size::HaveSize;
+    use crate::rustrict_scope::RustrictScope;
+    use expectest::prelude::*;
+
+    fn validate(roles: Vec<&str>) -> anyhow::Result<()> {
+        let scope = RustrictScope::new();
+        scope.validate_string("Roles", |s| s.must(roles.clone(), HaveSize::with_exact_size(1)));
+        scope.validate_string("Admins", |s| s.must(roles, HaveSize::with_exact_size(2)));
+        scope.into_result()?;
+        Ok(())
+    }
+
+    #[test]
+    fn should_recover_every_failure_of_a_scope() {
+        let error = validate(vec![]).unwrap_err().context("could not register the user");
+
+        let messages: Vec<String> =
+            error.constraint_failures().iter().map(|e| e.message()).collect();
+
+        expect!(messages).to(be_equal_to(vec![
+            "Roles: expected size 1, got 0".to_string(),
+            "Admins: expected size 2, got 0".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn should_keep_the_failure_of_a_field() {
+        let error = ConstraintError::new(|| "Email must not be empty".to_string());
+
+        let error = error.context_field("user.email");
+
+        expect!(error.to_string()).to(be_equal_to("invalid field user.email"));
+        expect!(error.constraint_failures().len()).to(be_equal_to(1));
+    }
+
+    #[test]
+    fn should_not_find_failures_in_other_errors() {
+        let error = anyhow::anyhow!("connection refused");
+
+        expect!(error.constraint_failures().is_empty()).to(be_true());
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Validation of many records with the same rules.
+//!
+//! Both functions run the rules of each item in a fresh `RustrictScope` and return one aggregated
+//! result per item, like mapping a Kotlin list through a validation block:
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! let rows = vec![vec!["admin"], vec![], vec!["viewer", "viewer"]];
+//!
+//! let outcomes = rustrict::validate_batch(&rows, |scope, row| {
+//!     scope.validate_string("Row must not be empty", |s| s.must(row, NotBeEmpty));
+//!     scope.validate_string("Row must not repeat", |s| s.must(row, HaveNoDuplicates));
+//! });
+//!
+//! assert!(outcomes[0].is_ok());
+//! assert!(outcomes[1].is_err() && outcomes[2].is_err());
+//! ```
+use crate::errors::composite_error::CompositeError;
+use crate::rustrict_scope::RustrictScope;
+
+/// Validates every item with the same rules, returning the outcome of each item.
+///
+/// # Parameters:
+/// - `items`: The items to validate.
+/// - `rules`: A closure that declares the rules of an item through its scope.
+///
+/// # Returns:
+/// The outcome of each item, in the order of `items`.
+pub fn validate_batch<I, F>(items: I, rules: F) -> Vec<Result<(), CompositeError>>
+where
+    I: IntoIterator,
+    F: FnMut(&mut RustrictScope, I::Item),
+{
+    RustrictScope::new().validate_iter(items, rules).collect()
+}
+
+/// Validates items with the same rules until a total number of failed rules is reached.
+///
+/// The item whose failures reach the limit is the last one validated, so the returned outcomes
+/// may be fewer than the items. This bounds the work and the size of the report on inputs that
+/// are wholly invalid, e.g. a file with the wrong columns.
+///
+/// # Parameters:
+/// - `items`: The items to validate.
+/// - `max_failures`: The number of failed rules, across all items, after which validation stops.
+/// - `rules`: A closure that declares the rules of an item through its scope.
+///
+/// # Returns:
+/// The outcome of each validated item, in the order of `items`.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// let rows = vec![vec![], vec![], vec!["admin"]];
+///
+/// let outcomes = rustrict::validate_batch_with_limit(&rows, 2, |scope, row| {
+///     scope.validate_string("Row must not be empty", |s| s.must(row, NotBeEmpty));
+/// });
+///
+/// assert_eq!(outcomes.len(), 2);
+/// ```
+pub fn validate_batch_with_limit<I, F>(
+    items: I,
+    max_failures: usize,
+    rules: F,
+) -> Vec<Result<(), CompositeError>>
+where
+    I: IntoIterator,
+    F: FnMut(&mut RustrictScope, I::Item),
+{
+    let scope = RustrictScope::new();
+    let mut outcomes = scope.validate_iter(items, rules);
+    let mut results = Vec::new();
+    let mut failures = 0;
+    while failures < max_failures {
+        let Some(outcome) = outcomes.next() else {
+            break;
+        };
+        failures += outcome.as_ref().err().map_or(0, |error| error.errors().len());
+        results.push(outcome);
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::have_size::HaveSize;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    /// Declares two rules on a row, which fail for rows of other sizes than 1 and 2 respectively.
+    fn rules(scope: &mut RustrictScope, row: &Vec<u8>) {
+        scope.validate_string("One", |s| s.must(row, HaveSize::with_exact_size(1)));
+        scope.validate_string("Two", |s| s.must(row, HaveSize::with_exact_size(2)));
+    }
+
+    proptest! {
+        #[test]
+        fn should_return_an_outcome_per_item(rows: Vec<Vec<u8>>) {
+            let outcomes = validate_batch(&rows, rules);
+
+            expect!(outcomes.len()).to(be_equal_to(rows.len()));
+            for (row, outcome) in rows.iter().zip(outcomes) {
+                let expected = if matches!(row.len(), 1 | 2) { 1 } else { 2 };
+                expect!(outcome.unwrap_err().errors().len()).to(be_equal_to(expected));
+            }
+        }
+
+        #[test]
+        fn should_stop_once_the_limit_is_reached(
+            rows in proptest::collection::vec(proptest::collection::vec(any::<u8>(), 3), 0..20),
+            limit in 0usize..10,
+        ) {
+            let outcomes = validate_batch_with_limit(&rows, limit, rules);
+
+            expect!(outcomes.len()).to(be_equal_to(rows.len().min(limit.div_ceil(2))));
+        }
+    }
+
+    #[test]
+    fn should_count_every_failed_rule_towards_the_limit() {
+        let rows = vec![vec![1], vec![], vec![1, 2]];
+
+        let outcomes = validate_batch_with_limit(&rows, 3, rules);
+
+        expect!(outcomes.len()).to(be_equal_to(2));
+        expect!(outcomes[1].as_ref().unwrap_err().errors().len()).to(be_equal_to(2));
+    }
+}
@@ -0,0 +1,304 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Validators loaded from TOML or YAML rules files.
+//!
+//! This module is available with the `config` feature. A rules file lists, for each field of a
+//! JSON document, the rules that the field must satisfy, so limits can be tweaked without
+//! recompiling the service:
+//!
+//! ```toml
+//! [[fields]]
+//! path = "/roles"
+//! rules = [
+//!     { message = "Roles must not be empty", constraint = "not_be_empty" },
+//!     { message = "Roles must be at most 3", constraint = "max_size", size = 3 },
+//!     { message = "Roles must be known", constraint = "be_subset_of", elements = ["admin", "viewer"] },
+//! ]
+//!
+//! [[fields]]
+//! path = "/username"
+//! rules = [{ message = "Username must be short", constraint = "max_length", length = 20 }]
+//!
+//! [[fields]]
+//! path = "/age"
+//! rules = [{ message = "Age must be valid", constraint = "be_in_range", min = 0, max = 150 }]
+//! ```
+//!
+//! Arrays are checked with the collection constraints, strings with `have_length`, `min_length`
+//! and `max_length`, and numbers with `be_in_range`, whose `min` and `max` are both optional.
+//!
+//! Fields are addressed by JSON pointers, like in `JsonScope`, and rules use the same format as
+//! the `wasm` bindings.
+use crate::errors::composite_error::CompositeError;
+use crate::rules::Rule;
+use crate::rustrict_scope::RustrictScope;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// The rules of a single field of the validated documents.
+#[derive(Debug, Deserialize)]
+struct FieldRules {
+    /// The JSON pointer of the field.
+    path: String,
+    rules: Vec<Rule>,
+}
+
+/// A validator built from a rules file.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::config::ConfigValidator;
+/// # use serde_json::json;
+/// let validator = ConfigValidator::from_yaml(r#"
+/// fields:
+///   - path: /roles
+///     rules:
+///       - { message: "Roles must not be empty", constraint: not_be_empty }
+/// "#).unwrap();
+///
+/// assert!(validator.validate(&json!({ "roles": ["admin"] })).is_ok());
+/// assert!(validator.validate(&json!({ "roles": [] })).is_err());
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ConfigValidator {
+    #[serde(default)]
+    fields: Vec<FieldRules>,
+}
+
+impl ConfigValidator {
+    /// Parses a validator from a TOML rules file.
+    pub fn from_toml(source: &str) -> Result<Self, ConfigError> {
+        toml::from_str(source).map_err(ConfigError::Toml)
+    }
+
+    /// Parses a validator from a YAML rules file.
+    pub fn from_yaml(source: &str) -> Result<Self, ConfigError> {
+        serde_yaml::from_str(source).map_err(ConfigError::Yaml)
+    }
+
+    /// Reads a validator from a rules file, choosing the format by its extension.
+    ///
+    /// # Parameters:
+    /// - `path`: The path of a `.toml`, `.yaml` or `.yml` file.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(ConfigError::Io)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&source),
+            Some("yaml" | "yml") => Self::from_yaml(&source),
+            _ => Err(ConfigError::UnknownFormat(path.to_path_buf())),
+        }
+    }
+
+    /// Validates a JSON document against the rules of every field.
+    ///
+    /// A field that is missing, or that doesn't have the shape checked by a rule, like a number
+    /// for `max_length`, fails that rule instead of aborting the validation, as it does with the
+    /// `wasm` bindings. The failure names the problem and the field, e.g.
+    /// `Username must be short: expected a string at /username`.
+    ///
+    /// # Returns:
+    /// - `Ok(())`: If every rule is satisfied.
+    /// - `Err(CompositeError)`: With the errors of the failed rules, in the order of the file.
+    pub fn validate(&self, document: &Value) -> Result<(), CompositeError> {
+        let scope = RustrictScope::new();
+        for field in &self.fields {
+            let value = document.pointer(&field.path);
+            for rule in &field.rules {
+                scope.validate_string(rule.message.clone(), |s| {
+                    let applied = value.ok_or("missing value");
+                    if let Err(problem) = applied.and_then(|value| rule.apply(s, value)) {
+                        s.constraint_with_message(
+                            || false,
+                            || format!("{}: {} at {}", rule.message, problem, field.path),
+                        );
+                    }
+                });
+            }
+        }
+        scope.into_result()
+    }
+}
+
+/// An error produced while loading a rules file.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not a valid TOML rules file.
+    Toml(toml::de::Error),
+    /// The file is not a valid YAML rules file.
+    Yaml(serde_yaml::Error),
+    /// The file extension is not one of the supported formats.
+    UnknownFormat(PathBuf),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "could not read the rules file: {}", error),
+            ConfigError::Toml(error) => write!(f, "invalid TOML rules file: {}", error),
+            ConfigError::Yaml(error) => write!(f, "invalid YAML rules file: {}", error),
+            ConfigError::UnknownFormat(path) => {
+                write!(f, "unknown rules file format: {}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io(error) => Some(error),
+            ConfigError::Toml(error) => Some(error),
+            ConfigError::Yaml(error) => Some(error),
+            ConfigError::UnknownFormat(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use serde_json::json;
+
+    const RULES: &str = r#"
+        [[fields]]
+        path = "/roles"
+        rules = [
+            { message = "Roles must not be empty", constraint = "not_be_empty" },
+            { message = "Roles must be at most 2", constraint = "max_size", size = 2 },
+        ]
+
+        [[fields]]
+        path = "/tags"
+        rules = [{ message = "Tags must be at least 1", constraint = "min_size", size = 1 }]
+    "#;
+
+    fn messages(result: Result<(), CompositeError>) -> Vec<String> {
+        match result {
+            Ok(()) => Vec::new(),
+            Err(error) => error.errors().iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn should_accept_documents_satisfying_every_rule() {
+        let validator = ConfigValidator::from_toml(RULES).unwrap();
+
+        let result = validator.validate(&json!({ "roles": ["admin"], "tags": ["a"] }));
+
+        expect!(result).to(be_ok());
+    }
+
+    #[test]
+    fn should_report_the_failed_rules_in_order() {
+        let validator = ConfigValidator::from_toml(RULES).unwrap();
+
+        let result = validator.validate(&json!({ "roles": ["a", "b", "c"], "tags": "a" }));
+
+        expect!(messages(result)).to(be_equal_to(vec![
+            "Roles must be at most 2: unexpected size 3".to_string(),
+            "Tags must be at least 1: expected an array at /tags".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn should_load_yaml_rules() {
+        let validator = ConfigValidator::from_yaml(
+            r#"
+            fields:
+              - path: /roles
+                rules:
+                  - { message: "Roles must be unique", constraint: have_no_duplicates }
+            "#,
+        )
+        .unwrap();
+
+        let result = validator.validate(&json!({}));
+
+        expect!(messages(result)).to(be_equal_to(vec![
+            "Roles must be unique: missing value at /roles".to_string(),
+        ]));
+    }
+
+    const SCALAR_RULES_TOML: &str = r#"
+        [[fields]]
+        path = "/username"
+        rules = [
+            { message = "Username must be at least 3", constraint = "min_length", length = 3 },
+            { message = "Username must be at most 8", constraint = "max_length", length = 8 },
+        ]
+
+        [[fields]]
+        path = "/age"
+        rules = [{ message = "Age must be valid", constraint = "be_in_range", min = 0, max = 150 }]
+    "#;
+
+    const SCALAR_RULES_YAML: &str = r#"
+        fields:
+          - path: /username
+            rules:
+              - { message: "Username must be at least 3", constraint: min_length, length: 3 }
+              - { message: "Username must be at most 8", constraint: max_length, length: 8 }
+          - path: /age
+            rules:
+              - { message: "Age must be valid", constraint: be_in_range, min: 0, max: 150 }
+    "#;
+
+    #[test]
+    fn should_check_the_length_of_strings_and_the_range_of_numbers() {
+        for validator in [
+            ConfigValidator::from_toml(SCALAR_RULES_TOML).unwrap(),
+            ConfigValidator::from_yaml(SCALAR_RULES_YAML).unwrap(),
+        ] {
+            expect!(validator.validate(&json!({ "username": "ada", "age": 36 }))).to(be_ok());
+            let result = validator.validate(&json!({ "username": "ab", "age": 151.5 }));
+
+            expect!(messages(result)).to(be_equal_to(vec![
+                "Username must be at least 3: expected at least 3 characters, got 2".to_string(),
+                "Age must be valid: expected a value between 0 and 150, got 151.5".to_string(),
+            ]));
+        }
+    }
+
+    #[test]
+    fn should_fail_the_rules_of_values_with_another_shape() {
+        let validator = ConfigValidator::from_toml(SCALAR_RULES_TOML).unwrap();
+
+        let result = validator.validate(&json!({ "username": ["ada"], "age": "36" }));
+
+        expect!(messages(result)).to(be_equal_to(vec![
+            "Username must be at least 3: expected a string at /username".to_string(),
+            "Username must be at most 8: expected a string at /username".to_string(),
+            "Age must be valid: expected a number at /age".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn should_reject_unknown_constraints() {
+        let rules = r#"
+            [[fields]]
+            path = "/roles"
+            rules = [{ message = "Roles must shine", constraint = "be_shiny" }]
+        "#;
+
+        expect!(ConfigValidator::from_toml(rules)).to(be_err());
+    }
+
+    #[test]
+    fn should_reject_unknown_file_formats() {
+        let path = std::env::temp_dir().join(format!("rustrict-rules-{}.ini", std::process::id()));
+        std::fs::write(&path, "").unwrap();
+
+        let result = ConfigValidator::from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        expect!(matches!(result, Err(ConfigError::UnknownFormat(_)))).to(be_true());
+    }
+}
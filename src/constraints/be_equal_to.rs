@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+use std::fmt::Debug;
+
+/// A constraint that checks if a value is equal to an expected one.
+///
+/// Its errors include both operands, e.g. `expected "admin", got "guest"`.
+#[derive(Debug)]
+pub struct BeEqualTo<T>(pub T);
+
+impl<T: PartialEq + Debug> Constraint<T> for BeEqualTo<T> {
+    fn validate(&self, value: &T) -> bool {
+        *value == self.0
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: expected {:?}", description, self.0);
+        ConstraintError::new(move || message.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &T, description: String) -> ConstraintError {
+        let message = format!("{}: expected {:?}, got {:?}", description, self.0, value);
+        ConstraintError::new(move || message.clone()).with_value(value)
+    }
+}
+
+/// A constraint that checks if a value is one of the allowed ones.
+///
+/// Its errors include the allowed values and the rejected one, e.g.
+/// `expected one of ["admin", "viewer"], got "guest"`, and list the allowed values as a hint.
+#[derive(Debug)]
+pub struct BeOneOf<T>(pub Vec<T>);
+
+impl<T: PartialEq + Debug> Constraint<T> for BeOneOf<T> {
+    fn validate(&self, value: &T) -> bool {
+        self.0.contains(value)
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: expected one of {:?}", description, self.0);
+        ConstraintError::new(move || message.clone()).with_hint(self.allowed_values_hint())
+    }
+
+    fn generate_exception_with_value(&self, value: &T, description: String) -> ConstraintError {
+        let message = format!("{}: expected one of {:?}, got {:?}", description, self.0, value);
+        ConstraintError::new(move || message.clone())
+            .with_value(value)
+            .with_hint(self.allowed_values_hint())
+    }
+}
+
+impl<T: Debug> BeOneOf<T> {
+    /// Lists the allowed values, e.g. `allowed values: "admin", "viewer"`.
+    fn allowed_values_hint(&self) -> String {
+        let values: Vec<String> = self.0.iter().map(|value| format!("{:?}", value)).collect();
+        format!("allowed values: {}", values.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_validate_equal_values(value: u8, expected: u8) {
+            expect!(BeEqualTo(expected).validate(&value)).to(be_equal_to(value == expected));
+        }
+
+        #[test]
+        fn should_validate_allowed_values(value: u8, allowed: Vec<u8>) {
+            let constraint = BeOneOf(allowed.clone());
+
+            expect!(constraint.validate(&value)).to(be_equal_to(allowed.contains(&value)));
+        }
+    }
+
+    #[test]
+    fn should_mention_both_operands() {
+        let exception = BeEqualTo("admin").generate_exception_with_value(&"guest", "Role".into());
+
+        expect!(exception.message()).to(be_equal_to(r#"Role: expected "admin", got "guest""#));
+        expect!(exception.value()).to(be_equal_to(Some(r#""guest""#)));
+    }
+
+    #[test]
+    fn should_mention_the_allowed_values() {
+        let exception = BeOneOf(vec![1, 2]).generate_exception_with_value(&3, "Level".into());
+
+        expect!(exception.message()).to(be_equal_to("Level: expected one of [1, 2], got 3"));
+        expect!(exception.hint()).to(be_some().value("allowed values: 1, 2"));
+    }
+}
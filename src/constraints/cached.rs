@@ -0,0 +1,217 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+/// The default maximum number of outcomes kept by a `Cached` constraint.
+pub const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+/// A constraint that memoizes the outcome of another constraint.
+///
+/// Outcomes are keyed on an owned copy of the validated value, so a value is only checked by the
+/// wrapped constraint the first time it is seen. This pays off for constraints that do expensive
+/// work, like compiling a regex or normalizing text, when the same value is validated repeatedly.
+///
+/// Clones share both the wrapped constraint and the cache, which lets a single `Cached` be reused
+/// across scopes:
+///
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # fn expensive_lookup(role: &str) -> bool { role == "admin" }
+/// # let scope = RustrictScope::new();
+/// # let role = "admin".to_string();
+/// let known_role = Cached::new(|role: &String| expensive_lookup(role));
+/// scope.validate_string("Role must be known", |s| s.must(role.clone(), known_role.clone()));
+/// ```
+///
+/// Values are compared by equality, not only by their hash, so distinct values never share an
+/// outcome. The cache keeps at most `max_entries` outcomes, `DEFAULT_MAX_ENTRIES` unless set
+/// through `with_max_entries`; once it is full, it is emptied before a new outcome is stored, which
+/// keeps untrusted input from growing it without bound.
+///
+/// # Type Parameters:
+/// - `C`: The type of the wrapped constraint.
+/// - `K`: The owned form of the validated values, like `String` for `str`, used as the key.
+pub struct Cached<C, K> {
+    constraint: Arc<C>,
+    outcomes: Arc<Mutex<HashMap<K, bool>>>,
+    max_entries: usize,
+}
+
+impl<C, K> Cached<C, K> {
+    /// Wraps a constraint, starting with an empty cache.
+    pub fn new(constraint: C) -> Self {
+        Self {
+            constraint: Arc::new(constraint),
+            outcomes: Arc::new(Mutex::new(HashMap::new())),
+            max_entries: DEFAULT_MAX_ENTRIES,
+        }
+    }
+
+    /// Sets the maximum number of outcomes kept by the cache.
+    ///
+    /// # Parameters:
+    /// - `max_entries`: The maximum number of cached outcomes. With `0`, nothing is cached.
+    ///
+    /// # Returns:
+    /// The same `Cached`, with its capacity set.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Returns the number of distinct values whose outcome is cached.
+    pub fn cached_count(&self) -> usize {
+        self.outcomes.lock().unwrap().len()
+    }
+
+    /// Discards every cached outcome.
+    pub fn clear(&self) {
+        self.outcomes.lock().unwrap().clear();
+    }
+}
+
+impl<C, K> Clone for Cached<C, K> {
+    fn clone(&self) -> Self {
+        Self {
+            constraint: self.constraint.clone(),
+            outcomes: self.outcomes.clone(),
+            max_entries: self.max_entries,
+        }
+    }
+}
+
+impl<T, K, C> Constraint<T> for Cached<C, K>
+where
+    T: ToOwned<Owned = K> + Hash + Eq + ?Sized,
+    K: Borrow<T> + Hash + Eq,
+    C: Constraint<T>,
+{
+    fn validate(&self, value: &T) -> bool {
+        if let Some(outcome) = self.outcomes.lock().unwrap().get(value) {
+            return *outcome;
+        }
+        let outcome = self.constraint.validate(value);
+        if self.max_entries > 0 {
+            let mut outcomes = self.outcomes.lock().unwrap();
+            if outcomes.len() >= self.max_entries {
+                outcomes.clear();
+            }
+            outcomes.insert(value.to_owned(), outcome);
+        }
+        outcome
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        self.constraint.generate_exception(description)
+    }
+
+    fn generate_exception_with_value(&self, value: &T, description: String) -> ConstraintError {
+        self.constraint.generate_exception_with_value(value, description)
+    }
+}
+
+impl<C: Debug, K> Debug for Cached<C, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cached({:?})", self.constraint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::have_size::HaveSize;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    proptest! {
+        #[test]
+        fn should_agree_with_the_wrapped_constraint(values: Vec<Vec<u8>>, size in 0usize..4) {
+            let cached = Cached::new(HaveSize::with_exact_size(size));
+            for value in &values {
+                expect!(cached.validate(value)).to(be_equal_to(value.len() == size));
+            }
+        }
+
+        #[test]
+        fn should_check_each_distinct_value_once(
+            values in proptest::collection::vec(0u8..8, 0..50)
+        ) {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let counter = calls.clone();
+            let cached = Cached::new(move |value: &u8| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                value.is_multiple_of(2)
+            });
+            let shared = cached.clone();
+
+            for value in &values {
+                expect!(shared.validate(value)).to(be_equal_to(value.is_multiple_of(2)));
+            }
+
+            let distinct = values.iter().collect::<std::collections::HashSet<_>>().len();
+            expect!(calls.load(Ordering::SeqCst)).to(be_equal_to(distinct));
+            expect!(cached.cached_count()).to(be_equal_to(distinct));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn should_keep_at_most_the_maximum_entries(
+            values in proptest::collection::vec(any::<u16>(), 0..100),
+            max_entries in 0usize..10
+        ) {
+            let cached = Cached::new(|value: &u16| value.is_multiple_of(3))
+                .with_max_entries(max_entries);
+
+            for value in &values {
+                expect!(cached.validate(value)).to(be_equal_to(value.is_multiple_of(3)));
+                expect!(cached.cached_count() <= max_entries).to(be_true());
+            }
+        }
+    }
+
+    /// A value whose hash ignores its contents, so that every value collides.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Colliding(u8);
+
+    impl Hash for Colliding {
+        fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+    }
+
+    #[test]
+    fn should_not_share_outcomes_between_colliding_values() {
+        let cached = Cached::new(|value: &Colliding| value.0 == 0);
+
+        expect!(cached.validate(&Colliding(0))).to(be_true());
+        expect!(cached.validate(&Colliding(1))).to(be_false());
+        expect!(cached.cached_count()).to(be_equal_to(2));
+    }
+
+    #[test]
+    fn should_forget_outcomes_when_cleared() {
+        let cached = Cached::new(HaveSize::with_exact_size(1));
+        cached.validate(&[1]);
+
+        cached.clear();
+
+        expect!(cached.cached_count()).to(be_equal_to(0));
+    }
+
+    #[test]
+    fn should_delegate_error_messages() {
+        let cached = Cached::new(HaveSize::with_exact_size(1));
+
+        let exception = cached.generate_exception_with_value(&[1, 2], "Tags".to_string());
+
+        expect!(exception.message()).to(be_equal_to("Tags: expected size 1, got 2"));
+    }
+}
@@ -0,0 +1,112 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use std::fmt::Debug;
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// A constraint that checks that a collection has no elements.
+pub struct BeEmpty;
+
+impl<T> Constraint<[T]> for BeEmpty {
+    fn validate(&self, value: &[T]) -> bool {
+        value.is_empty()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        ConstraintError::new(move || format!("{}: expected collection to be empty", description))
+    }
+
+    fn generate_exception_with_value(&self, value: &[T], description: String) -> ConstraintError {
+        let size = value.len();
+        ConstraintError::new(move || {
+            format!(
+                "{}: expected collection to be empty but it had {} elements",
+                description, size
+            )
+        })
+        .with_hint("remove every element")
+    }
+}
+
+impl Debug for BeEmpty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BeEmpty")
+    }
+}
+
+/// A constraint that checks that a collection has at least one element.
+pub struct NotBeEmpty;
+
+impl<T> Constraint<[T]> for NotBeEmpty {
+    fn validate(&self, value: &[T]) -> bool {
+        !value.is_empty()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        ConstraintError::new(move || {
+            format!("{}: expected collection to not be empty", description)
+        })
+        .with_hint("add at least one element")
+    }
+}
+
+impl Debug for NotBeEmpty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NotBeEmpty")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    mod when_expecting_an_empty_collection {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_only_empty_collections(collection: Vec<u8>) {
+                expect!(BeEmpty.validate(&collection)).to(be_equal_to(collection.is_empty()));
+            }
+
+            #[test]
+            fn should_report_the_actual_size(
+                collection in proptest::collection::vec(any::<u8>(), 1..100),
+                description in "[a-z ]{1,20}"
+            ) {
+                let exception = BeEmpty.generate_exception_with_value(&collection, description.clone());
+                expect!(exception.message()).to(be_equal_to(format!(
+                    "{}: expected collection to be empty but it had {} elements",
+                    description,
+                    collection.len()
+                )));
+            }
+        }
+    }
+
+    mod when_expecting_a_non_empty_collection {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_only_non_empty_collections(collection: Vec<u8>) {
+                expect!(NotBeEmpty.validate(&collection)).to(be_equal_to(!collection.is_empty()));
+            }
+
+            #[test]
+            fn should_generate_a_descriptive_message(description in "[a-z ]{1,20}") {
+                let exception = <NotBeEmpty as Constraint<[u8]>>::generate_exception(
+                    &NotBeEmpty, description.clone()
+                );
+                expect!(exception.message()).to(be_equal_to(
+                    format!("{}: expected collection to not be empty", description)
+                ));
+                expect!(exception.hint()).to(be_some().value("add at least one element"));
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use std::fmt::Debug;
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// A constraint that checks that a collection is sorted in non-decreasing order.
+pub struct BeSorted;
+
+impl BeSorted {
+    /// Returns the index of the first element that is smaller than its predecessor, if any.
+    pub fn first_unsorted_index<T: PartialOrd>(&self, value: &[T]) -> Option<usize> {
+        value
+            .windows(2)
+            .position(|pair| pair[1] < pair[0])
+            .map(|index| index + 1)
+    }
+}
+
+impl<T: PartialOrd> Constraint<[T]> for BeSorted {
+    fn validate(&self, value: &[T]) -> bool {
+        self.first_unsorted_index(value).is_none()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        ConstraintError::new(move || description.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &[T], description: String) -> ConstraintError {
+        unsorted_exception(self.first_unsorted_index(value), description)
+    }
+}
+
+impl Debug for BeSorted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BeSorted")
+    }
+}
+
+/// A constraint that checks that a collection is sorted in non-decreasing order of the keys
+/// produced by a key function.
+pub struct BeSortedBy<F> {
+    key_fn: F,
+}
+
+impl<F> BeSortedBy<F> {
+    /// Creates a new `BeSortedBy` constraint that compares elements by the given key function.
+    pub fn new(key_fn: F) -> Self {
+        Self { key_fn }
+    }
+
+    /// Returns the index of the first element whose key is smaller than its predecessor's, if any.
+    pub fn first_unsorted_index<T, K>(&self, value: &[T]) -> Option<usize>
+    where
+        F: Fn(&T) -> K,
+        K: PartialOrd,
+    {
+        value
+            .windows(2)
+            .position(|pair| (self.key_fn)(&pair[1]) < (self.key_fn)(&pair[0]))
+            .map(|index| index + 1)
+    }
+}
+
+impl<T, K, F> Constraint<[T]> for BeSortedBy<F>
+where
+    F: Fn(&T) -> K,
+    K: PartialOrd,
+{
+    fn validate(&self, value: &[T]) -> bool {
+        self.first_unsorted_index(value).is_none()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        ConstraintError::new(move || description.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &[T], description: String) -> ConstraintError {
+        unsorted_exception(self.first_unsorted_index(value), description)
+    }
+}
+
+impl<F> Debug for BeSortedBy<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BeSortedBy")
+    }
+}
+
+/// Builds the error for a sortedness violation, mentioning the offending index when there is one.
+fn unsorted_exception(index: Option<usize>, description: String) -> ConstraintError {
+    match index {
+        Some(index) => ConstraintError::new(move || {
+            format!("{}: element at index {} is out of order", description, index)
+        }),
+        None => ConstraintError::new(move || description.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    mod when_checking_natural_order {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_sorted_collections(mut collection: Vec<u8>) {
+                collection.sort();
+                expect!(BeSorted.validate(&collection)).to(be_true());
+            }
+
+            #[test]
+            fn should_agree_with_the_standard_library(collection: Vec<u8>) {
+                let result = BeSorted.validate(&collection);
+                expect!(result).to(be_equal_to(collection.is_sorted()));
+            }
+
+            #[test]
+            fn should_report_the_first_offending_index(
+                prefix in proptest::collection::vec(0u8..100, 0..10),
+                description in "[a-z ]{1,20}"
+            ) {
+                let mut collection = prefix;
+                collection.sort();
+                collection.push(100);
+                collection.push(0);
+                let index = collection.len() - 1;
+
+                let exception = BeSorted.generate_exception_with_value(&collection, description.clone());
+
+                expect!(exception.message()).to(be_equal_to(
+                    format!("{}: element at index {} is out of order", description, index)
+                ));
+            }
+        }
+    }
+
+    mod when_checking_by_key {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_collections_sorted_by_the_key(mut collection: Vec<(u8, u8)>) {
+                collection.sort_by_key(|pair| pair.1);
+                let constraint = BeSortedBy::new(|pair: &(u8, u8)| pair.1);
+                expect!(constraint.validate(&collection)).to(be_true());
+            }
+
+            #[test]
+            fn should_agree_with_the_standard_library(collection: Vec<(u8, u8)>) {
+                let constraint = BeSortedBy::new(|pair: &(u8, u8)| pair.1);
+                let result = constraint.validate(&collection);
+                expect!(result).to(be_equal_to(collection.is_sorted_by_key(|pair| pair.1)));
+            }
+        }
+
+        #[test]
+        fn should_report_the_first_offending_index() {
+            let constraint = BeSortedBy::new(|word: &&str| word.len());
+            let collection = vec!["a", "bb", "c", "dd"];
+
+            let exception = constraint.generate_exception_with_value(&collection, "Words".to_string());
+
+            expect!(exception.message()).to(be_equal_to("Words: element at index 2 is out of order"));
+        }
+    }
+}
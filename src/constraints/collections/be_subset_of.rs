@@ -0,0 +1,168 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// A constraint that checks that every element of a collection belongs to a reference set.
+#[derive(Debug)]
+pub struct BeSubsetOf<T> {
+    pub(crate) reference: Vec<T>,
+}
+
+impl<T: Eq + Hash> BeSubsetOf<T> {
+    /// Creates a `BeSubsetOf` constraint from the allowed elements.
+    pub fn new(reference: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            reference: unique(reference),
+        }
+    }
+
+    /// Returns the elements of the collection that are not part of the reference set, in order of
+    /// first appearance.
+    pub fn extraneous_elements<'a>(&self, value: &'a [T]) -> Vec<&'a T> {
+        difference(value, &self.reference)
+    }
+}
+
+impl<T: Eq + Hash + Debug> Constraint<[T]> for BeSubsetOf<T> {
+    fn validate(&self, value: &[T]) -> bool {
+        self.extraneous_elements(value).is_empty()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        ConstraintError::new(move || description.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &[T], description: String) -> ConstraintError {
+        let message = format!(
+            "{}: extraneous elements {:?}",
+            description,
+            self.extraneous_elements(value)
+        );
+        let allowed: Vec<String> = self.reference.iter().map(|e| format!("{:?}", e)).collect();
+        ConstraintError::new(move || message.clone())
+            .with_hint(format!("allowed elements: {}", allowed.join(", ")))
+    }
+}
+
+/// A constraint that checks that a collection contains every element of a reference set.
+#[derive(Debug)]
+pub struct BeSupersetOf<T> {
+    pub(crate) reference: Vec<T>,
+}
+
+impl<T: Eq + Hash> BeSupersetOf<T> {
+    /// Creates a `BeSupersetOf` constraint from the required elements.
+    pub fn new(reference: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            reference: unique(reference),
+        }
+    }
+
+    /// Returns the elements of the reference set that are missing from the collection, in the
+    /// order they were given.
+    pub fn missing_elements(&self, value: &[T]) -> Vec<&T> {
+        difference(&self.reference, value)
+    }
+}
+
+impl<T: Eq + Hash + Debug> Constraint<[T]> for BeSupersetOf<T> {
+    fn validate(&self, value: &[T]) -> bool {
+        self.missing_elements(value).is_empty()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        ConstraintError::new(move || description.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &[T], description: String) -> ConstraintError {
+        let message = format!(
+            "{}: missing elements {:?}",
+            description,
+            self.missing_elements(value)
+        );
+        ConstraintError::new(move || message.clone())
+    }
+}
+
+/// Removes repeated elements while keeping the order of their first appearance.
+fn unique<T: PartialEq>(elements: impl IntoIterator<Item = T>) -> Vec<T> {
+    let mut result: Vec<T> = Vec::new();
+    for element in elements {
+        if !result.contains(&element) {
+            result.push(element);
+        }
+    }
+    result
+}
+
+/// Returns the distinct elements of `left` that do not appear in `right`.
+fn difference<'a, T: Eq + Hash>(left: &'a [T], right: &[T]) -> Vec<&'a T> {
+    let right: HashSet<&T> = right.iter().collect();
+    let mut seen = HashSet::new();
+    left.iter()
+        .filter(|element| !right.contains(element) && seen.insert(*element))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    mod when_checking_a_subset {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_collections_within_the_reference(
+                reference: HashSet<u8>, collection: Vec<u8>
+            ) {
+                let result = BeSubsetOf::new(reference.clone()).validate(&collection);
+                expect!(result).to(be_equal_to(collection.iter().all(|e| reference.contains(e))));
+            }
+        }
+
+        #[test]
+        fn should_list_the_extraneous_elements() {
+            let constraint = BeSubsetOf::new(["red", "green", "blue"]);
+            let tags = vec!["red", "pink", "blue", "pink", "teal"];
+
+            let exception = constraint.generate_exception_with_value(&tags, "Tags".to_string());
+
+            expect!(exception.message()).to(be_equal_to(r#"Tags: extraneous elements ["pink", "teal"]"#));
+            expect!(exception.hint())
+                .to(be_some().value(r#"allowed elements: "red", "green", "blue""#));
+        }
+    }
+
+    mod when_checking_a_superset {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_collections_containing_the_reference(
+                reference: HashSet<u8>, collection: Vec<u8>
+            ) {
+                let result = BeSupersetOf::new(reference.clone()).validate(&collection);
+                expect!(result).to(be_equal_to(reference.iter().all(|e| collection.contains(e))));
+            }
+        }
+
+        #[test]
+        fn should_list_the_missing_elements() {
+            let constraint = BeSupersetOf::new(["read", "write", "read", "delete"]);
+            let permissions = vec!["write"];
+
+            let exception = constraint.generate_exception_with_value(&permissions, "Permissions".to_string());
+
+            expect!(exception.message()).to(be_equal_to(r#"Permissions: missing elements ["read", "delete"]"#));
+        }
+    }
+}
@@ -6,7 +6,7 @@ use crate::constraints::constraint::Constraint;
 use crate::errors::collection_constraint_error::CollectionConstraintError;
 use crate::errors::constraint_error::ConstraintError;
 
-pub trait CollectionConstraint<T>: Constraint<Vec<T>> {
+pub trait CollectionConstraint<T>: Constraint<[T]> {
     fn generate_exception(&self, description: String) -> CollectionConstraintError {
         CollectionConstraintError::new(move || description.clone())
     }
@@ -0,0 +1,137 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use std::fmt::Debug;
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// A constraint that checks that a collection contains a specific element.
+#[derive(Debug)]
+pub struct ContainElement<T>(pub T);
+
+impl<T: PartialEq + Debug> Constraint<[T]> for ContainElement<T> {
+    fn validate(&self, value: &[T]) -> bool {
+        value.contains(&self.0)
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: missing element {:?}", description, self.0);
+        ConstraintError::new(move || message.clone())
+    }
+}
+
+/// A constraint that checks that a collection contains every one of the given elements.
+#[derive(Debug)]
+pub struct ContainAll<T>(pub Vec<T>);
+
+impl<T: PartialEq> ContainAll<T> {
+    /// Returns the expected elements that are not present in the given collection.
+    pub fn missing_elements<'a>(&'a self, value: &[T]) -> Vec<&'a T> {
+        self.0
+            .iter()
+            .filter(|element| !value.contains(element))
+            .collect()
+    }
+}
+
+impl<T: PartialEq + Debug> Constraint<[T]> for ContainAll<T> {
+    fn validate(&self, value: &[T]) -> bool {
+        self.0.iter().all(|element| value.contains(element))
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        ConstraintError::new(move || description.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &[T], description: String) -> ConstraintError {
+        let missing = self.missing_elements(value);
+        if missing.is_empty() {
+            return self.generate_exception(description);
+        }
+        let message = format!("{}: missing elements {:?}", description, missing);
+        ConstraintError::new(move || message.clone())
+    }
+}
+
+/// A constraint that checks that a collection contains at least one of the given elements.
+#[derive(Debug)]
+pub struct ContainAny<T>(pub Vec<T>);
+
+impl<T: PartialEq + Debug> Constraint<[T]> for ContainAny<T> {
+    fn validate(&self, value: &[T]) -> bool {
+        self.0.iter().any(|element| value.contains(element))
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: expected any of {:?}", description, self.0);
+        ConstraintError::new(move || message.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    mod when_checking_a_single_element {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_collections_containing_the_element(collection: Vec<u8>, element: u8) {
+                let result = ContainElement(element).validate(&collection);
+                expect!(result).to(be_equal_to(collection.contains(&element)));
+            }
+
+            #[test]
+            fn should_mention_the_missing_element(element: u8, description in "[a-z ]{1,20}") {
+                let exception = <ContainElement<u8> as Constraint<[u8]>>::generate_exception(
+                    &ContainElement(element), description.clone()
+                );
+                expect!(exception.message()).to(be_equal_to(
+                    format!("{}: missing element {}", description, element)
+                ));
+            }
+        }
+    }
+
+    mod when_checking_all_elements {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_collections_containing_every_element(
+                collection: Vec<u8>, elements: Vec<u8>
+            ) {
+                let result = ContainAll(elements.clone()).validate(&collection);
+                expect!(result).to(be_equal_to(elements.iter().all(|e| collection.contains(e))));
+            }
+        }
+
+        #[test]
+        fn should_list_the_missing_elements() {
+            let constraint = ContainAll(vec!["admin", "editor", "viewer"]);
+            let roles = vec!["viewer"];
+
+            let exception = constraint.generate_exception_with_value(&roles, "Roles".to_string());
+
+            expect!(exception.message()).to(be_equal_to(r#"Roles: missing elements ["admin", "editor"]"#));
+        }
+    }
+
+    mod when_checking_any_element {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_validate_collections_containing_some_element(
+                collection: Vec<u8>, elements: Vec<u8>
+            ) {
+                let result = ContainAny(elements.clone()).validate(&collection);
+                expect!(result).to(be_equal_to(elements.iter().any(|e| collection.contains(e))));
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// A constraint that checks that a collection contains no repeated elements.
+pub struct HaveNoDuplicates;
+
+impl HaveNoDuplicates {
+    /// Returns the index of the first element that repeats an earlier element, if any.
+    pub fn first_duplicate_index<T: Eq + Hash>(&self, value: &[T]) -> Option<usize> {
+        let mut seen = HashSet::with_capacity(value.len());
+        value.iter().position(|element| !seen.insert(element))
+    }
+}
+
+impl<T: Eq + Hash> Constraint<[T]> for HaveNoDuplicates {
+    fn validate(&self, value: &[T]) -> bool {
+        self.first_duplicate_index(value).is_none()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        ConstraintError::new(move || description.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &[T], description: String) -> ConstraintError {
+        match self.first_duplicate_index(value) {
+            Some(index) => ConstraintError::new(move || {
+                format!("{}: element at index {} is a duplicate", description, index)
+            })
+            .with_hint(format!("remove the element at index {}", index)),
+            None => ConstraintError::new(move || description.clone()),
+        }
+    }
+}
+
+impl Debug for HaveNoDuplicates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HaveNoDuplicates")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_validate_collections_without_duplicates(collection: HashSet<u8>) {
+            let collection: Vec<u8> = collection.into_iter().collect();
+            expect!(HaveNoDuplicates.validate(&collection)).to(be_true());
+        }
+
+        #[test]
+        fn should_reject_collections_with_duplicates(
+            collection in proptest::collection::vec(any::<u8>(), 1..100),
+            index in any::<prop::sample::Index>()
+        ) {
+            let mut collection = collection;
+            let duplicate = collection[index.index(collection.len())];
+            collection.push(duplicate);
+            expect!(HaveNoDuplicates.validate(&collection)).to(be_false());
+        }
+
+        #[test]
+        fn should_report_the_first_offending_index(
+            unique in proptest::collection::hash_set(any::<u8>(), 1..50),
+            description in "[a-z ]{1,20}"
+        ) {
+            let mut collection: Vec<u8> = unique.into_iter().collect();
+            let index = collection.len();
+            collection.push(collection[0]);
+
+            let exception = HaveNoDuplicates.generate_exception_with_value(&collection, description.clone());
+
+            expect!(exception.message()).to(be_equal_to(
+                format!("{}: element at index {} is a duplicate", description, index)
+            ));
+            expect!(exception.hint().map(str::to_string))
+                .to(be_some().value(format!("remove the element at index {}", index)));
+        }
+    }
+}
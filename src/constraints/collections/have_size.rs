@@ -2,13 +2,15 @@
  * Copyright (c) 2024, Ignacio Slater M.
  * 2-Clause BSD License.
  */
+use std::cmp::Ordering;
 use std::fmt::Debug;
 use crate::constraints::constraint::Constraint;
 use crate::errors::constraint_error::ConstraintError;
 use std::sync::Arc;
 
 pub struct HaveSize {
-    predicate: Arc<dyn Fn(usize) -> bool + Send + Sync>,
+    pub(crate) predicate: Arc<dyn Fn(usize) -> bool + Send + Sync>,
+    pub(crate) expected_size: Option<usize>,
 }
 
 impl HaveSize {
@@ -19,23 +21,49 @@ impl HaveSize {
     {
         Self {
             predicate: Arc::new(predicate),
+            expected_size: None,
         }
     }
 
     /// Creates a `HaveSize` constraint for an exact size.
     pub fn with_exact_size(size: usize) -> Self {
-        Self::new(move |s| s == size)
+        Self {
+            expected_size: Some(size),
+            ..Self::new(move |s| s == size)
+        }
     }
 }
 
-impl<T> Constraint<Vec<T>> for HaveSize {
-    fn validate(&self, value: &Vec<T>) -> bool {
+impl<T> Constraint<[T]> for HaveSize {
+    fn validate(&self, value: &[T]) -> bool {
         (self.predicate)(value.len())
     }
 
     fn generate_exception(&self, description: String) -> ConstraintError {
         ConstraintError::new(move || description.clone())
     }
+
+    fn generate_exception_with_value(&self, value: &[T], description: String) -> ConstraintError {
+        let actual = value.len();
+        let message = match self.expected_size {
+            Some(expected) => format!("{}: expected size {}, got {}", description, expected, actual),
+            None => format!("{}: unexpected size {}", description, actual),
+        };
+        let hint = self.expected_size.and_then(|expected| match actual.cmp(&expected) {
+            Ordering::Less => Some(format!("add {}", elements(expected - actual))),
+            Ordering::Greater => Some(format!("remove {}", elements(actual - expected))),
+            Ordering::Equal => None,
+        });
+        ConstraintError::new(move || message.clone()).with_optional_hint(hint)
+    }
+}
+
+/// Describes a number of elements, e.g. `1 element` or `2 elements`.
+fn elements(count: usize) -> String {
+    match count {
+        1 => "1 element".to_string(),
+        count => format!("{} elements", count),
+    }
 }
 
 impl Debug for HaveSize {
@@ -65,10 +93,36 @@ mod tests {
             fn should_generate_an_exception_with_the_specified_description(size: usize, description: String) {
                 let constraint = HaveSize::with_exact_size(size);
 
-                let exception = <HaveSize as Constraint<Vec<u8>>>::generate_exception(&constraint, description.clone());
+                let exception = <HaveSize as Constraint<[u8]>>::generate_exception(&constraint, description.clone());
 
                 expect!(exception.message()).to(be_equal_to(description));
             }
+
+            #[test]
+            fn should_report_the_expected_and_actual_sizes(
+                size in 0usize..100,
+                collection: Vec<u8>,
+                description in "[a-z ]{1,20}"
+            ) {
+                let constraint = HaveSize::with_exact_size(size);
+
+                let exception = constraint.generate_exception_with_value(&collection, description.clone());
+
+                expect!(exception.message()).to(be_equal_to(format!(
+                    "{}: expected size {}, got {}", description, size, collection.len()
+                )));
+            }
+        }
+
+        #[test]
+        fn should_hint_how_many_elements_to_add_or_remove() {
+            let constraint = HaveSize::with_exact_size(2);
+
+            let missing = constraint.generate_exception_with_value(&[1], "Size".to_string());
+            let extra = constraint.generate_exception_with_value(&[1, 2, 3, 4], "Size".to_string());
+
+            expect!(missing.hint()).to(be_some().value("add 1 element"));
+            expect!(extra.hint()).to(be_some().value("remove 2 elements"));
         }
     }
 
@@ -99,6 +153,17 @@ mod tests {
 
                 expect!(result).to(be_false());
             }
+
+            #[test]
+            fn should_report_the_actual_size(collection: Vec<u8>) {
+                let constraint = HaveSize::new(|size| size <= 5);
+
+                let exception = constraint.generate_exception_with_value(&collection, "Size".to_string());
+
+                expect!(exception.message()).to(be_equal_to(
+                    format!("Size: unexpected size {}", collection.len())
+                ));
+            }
         }
     }
 }
@@ -2,5 +2,10 @@
  * Copyright (c) 2024, Ignacio Slater M.
  * 2-Clause BSD License.
  */
+pub mod be_empty;
+pub mod be_sorted;
+pub mod be_subset_of;
 mod collection_constraint;
+pub mod contain_element;
+pub mod have_no_duplicates;
 pub mod have_size;
@@ -5,7 +5,7 @@
 
 use crate::errors::constraint_error::ConstraintError;
 
-pub trait Constraint<T> {
+pub trait Constraint<T: ?Sized> {
     /// The validation function that checks if the value meets the constraint criteria.
     fn validate(&self, value: &T) -> bool;
 
@@ -19,14 +19,32 @@ pub trait Constraint<T> {
     /// - `description`: A string describing the reason for the exception.
     /// - Returns: A `ConstraintError` containing the provided description.
     fn generate_exception(&self, description: String) -> ConstraintError;
-    
-    fn generate_error_message(&self, message: &str) -> String {
-        format!("{}: {}", message, self.generate_exception(message.to_string()))
+
+    /// Generates a `ConstraintError` for a specific value that failed the constraint.
+    ///
+    /// Constraints that can describe *why* a value was rejected (e.g., the index of the first
+    /// offending element) should override this method. By default, the value is ignored and the
+    /// error is generated by `generate_exception`.
+    ///
+    /// - `value`: The value that was validated.
+    /// - `description`: A string describing the reason for the exception.
+    /// - Returns: A `ConstraintError` describing the violation.
+    fn generate_exception_with_value(&self, _value: &T, description: String) -> ConstraintError {
+        self.generate_exception(description)
+    }
+
+    fn generate_error_message(&self, value: &T, message: &str) -> String {
+        format!(
+            "{}: {}",
+            message,
+            self.generate_exception_with_value(value, message.to_string())
+        )
     }
 }
 
 impl<T, F> Constraint<T> for F
 where
+    T: ?Sized,
     F: Fn(&T) -> bool,
 {
     fn validate(&self, value: &T) -> bool {
@@ -37,3 +55,56 @@ where
         ConstraintError::new(move || description.clone())
     }
 }
+
+/// A value that can be checked by the constraints of `T`.
+///
+/// This lets rules accept owned and borrowed values alike: a constraint written against `[T]`
+/// checks `Vec<T>`, `&Vec<T>`, `&[T]` and arrays, and one written against `str` checks `String`,
+/// `&String` and `&str`, without cloning them. Every value can be checked by the constraints of
+/// its own type, and every reference by the constraints of the referenced type.
+pub trait AsSubject<T: ?Sized> {
+    /// Returns the view of the value checked by the constraint.
+    fn as_subject(&self) -> &T;
+}
+
+impl<T> AsSubject<T> for T {
+    fn as_subject(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> AsSubject<T> for &T {
+    fn as_subject(&self) -> &T {
+        self
+    }
+}
+
+impl<T> AsSubject<[T]> for Vec<T> {
+    fn as_subject(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsSubject<[T]> for &Vec<T> {
+    fn as_subject(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsSubject<[T]> for [T; N] {
+    fn as_subject(&self) -> &[T] {
+        self
+    }
+}
+
+impl AsSubject<str> for String {
+    fn as_subject(&self) -> &str {
+        self
+    }
+}
+
+impl AsSubject<str> for &String {
+    fn as_subject(&self) -> &str {
+        self
+    }
+}
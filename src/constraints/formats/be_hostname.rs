@@ -0,0 +1,137 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::constraints::strings::whitespace_hint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// The maximum length of a hostname, without its trailing dot.
+const MAX_LENGTH: usize = 253;
+/// The maximum length of each dot-separated label of a hostname.
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// A constraint that checks if a string is a valid hostname, following RFC 1123.
+///
+/// A hostname is a sequence of labels separated by dots, like `api.example.com`. Each label has 1
+/// to 63 ASCII letters, digits or hyphens, and does not start or end with a hyphen. The whole name
+/// has at most 253 characters, and may end with a dot.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Host must be valid", |s| s.must("api.example.com", BeHostname));
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BeHostname;
+
+impl BeHostname {
+    /// Finds why a value is not a hostname.
+    ///
+    /// # Returns:
+    /// A description of the problem, or `None` if the value is a hostname.
+    fn problem(&self, value: &str) -> Option<String> {
+        let name = value.strip_suffix('.').unwrap_or(value);
+        if name.is_empty() {
+            return Some("empty name".to_string());
+        }
+        if name.len() > MAX_LENGTH {
+            return Some(format!("longer than {} characters", MAX_LENGTH));
+        }
+        name.split('.').find_map(|label| {
+            if label.is_empty() {
+                Some("empty label".to_string())
+            } else if label.len() > MAX_LABEL_LENGTH {
+                Some(format!("label longer than {} characters", MAX_LABEL_LENGTH))
+            } else if label.starts_with('-') || label.ends_with('-') {
+                Some(format!("label {:?} starts or ends with a hyphen", label))
+            } else {
+                label
+                    .chars()
+                    .find(|c| !c.is_ascii_alphanumeric() && *c != '-')
+                    .map(|c| format!("invalid character {:?}", c))
+            }
+        })
+    }
+}
+
+impl Constraint<str> for BeHostname {
+    fn validate(&self, value: &str) -> bool {
+        self.problem(value).is_none()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: expected a hostname", description);
+        ConstraintError::new(move || message.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &str, description: String) -> ConstraintError {
+        let message = match self.problem(value) {
+            Some(problem) => format!(
+                "{}: expected a hostname, got {:?} ({})",
+                description, value, problem
+            ),
+            None => format!("{}: expected a hostname, got {:?}", description, value),
+        };
+        ConstraintError::new(move || message.clone())
+            .with_value(value)
+            .with_optional_hint(whitespace_hint(value, |value| self.validate(value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::not::not;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_accept_well_formed_names(
+            labels in proptest::collection::vec("[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?", 1..4)
+        ) {
+            expect!(BeHostname.validate(&labels.join("."))).to(be_true());
+        }
+    }
+
+    #[test]
+    fn should_accept_a_trailing_dot() {
+        expect!(BeHostname.validate("example.com.")).to(be_true());
+        expect!(BeHostname.validate(".")).to(be_false());
+    }
+
+    #[test]
+    fn should_reject_malformed_labels() {
+        expect!(BeHostname.validate("example..com")).to(be_false());
+        expect!(BeHostname.validate("-example.com")).to(be_false());
+        expect!(BeHostname.validate("exam_ple.com")).to(be_false());
+        expect!(BeHostname.validate(&"a".repeat(64))).to(be_false());
+        expect!(BeHostname.validate(&["a"; 128].join("."))).to(be_false());
+    }
+
+    #[test]
+    fn should_explain_the_failure() {
+        let exception = BeHostname.generate_exception_with_value("my_host", "Host".to_string());
+
+        expect!(exception.message())
+            .to(be_equal_to(r#"Host: expected a hostname, got "my_host" (invalid character '_')"#));
+    }
+
+    #[test]
+    fn should_describe_a_valid_name_without_a_problem() {
+        let exception = not(BeHostname).generate_exception_with_value("example.com", "Host".into());
+
+        expect!(exception.message())
+            .to(be_equal_to(r#"Host: expected NOT a hostname, got "example.com""#));
+    }
+
+    #[test]
+    fn should_hint_at_surrounding_whitespace() {
+        let exception = BeHostname.generate_exception_with_value("example.com\n", "Host".into());
+
+        expect!(exception.hint()).to(be_some().value("try removing trailing whitespace"));
+    }
+}
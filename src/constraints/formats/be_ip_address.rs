@@ -0,0 +1,209 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::constraints::strings::whitespace_hint;
+use crate::errors::constraint_error::ConstraintError;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// A constraint that checks if a string is an IPv4 address in dotted decimal notation, like
+/// `192.168.0.1`.
+#[derive(Debug, Clone, Copy)]
+pub struct BeIpv4;
+
+/// A constraint that checks if a string is an IPv6 address, like `::1` or
+/// `2001:db8::8a2e:370:7334`.
+#[derive(Debug, Clone, Copy)]
+pub struct BeIpv6;
+
+/// A constraint that checks if a string is either an IPv4 or an IPv6 address.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Bind address must be an IP", |s| s.must("::1", BeIpAddress));
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BeIpAddress;
+
+/// Implements `Constraint<str>` for an address constraint, accepting the strings that parse as
+/// the given `std::net` type.
+macro_rules! impl_address_constraint {
+    ($constraint:ty, $address:ty, $expected:literal) => {
+        impl Constraint<str> for $constraint {
+            fn validate(&self, value: &str) -> bool {
+                value.parse::<$address>().is_ok()
+            }
+
+            fn generate_exception(&self, description: String) -> ConstraintError {
+                let message = format!("{}: expected {}", description, $expected);
+                ConstraintError::new(move || message.clone())
+            }
+
+            fn generate_exception_with_value(
+                &self,
+                value: &str,
+                description: String,
+            ) -> ConstraintError {
+                let message = format!("{}: expected {}, got {:?}", description, $expected, value);
+                ConstraintError::new(move || message.clone())
+                    .with_value(value)
+                    .with_optional_hint(whitespace_hint(value, |value| self.validate(value)))
+            }
+        }
+    };
+}
+
+impl_address_constraint!(BeIpv4, Ipv4Addr, "an IPv4 address");
+impl_address_constraint!(BeIpv6, Ipv6Addr, "an IPv6 address");
+impl_address_constraint!(BeIpAddress, IpAddr, "an IP address");
+
+/// A constraint that checks if a string is an IP address within a CIDR block, like `10.0.0.0/8`.
+///
+/// The block is parsed on every check; an invalid block rejects every value, and its errors say
+/// so. Addresses are only contained in blocks of their own family, so `::ffff:10.0.0.1` is not in
+/// `10.0.0.0/8`.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Peer must be private", |s| s.must("10.1.2.3", BeInCidr("10.0.0.0/8")));
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BeInCidr<S>(pub S);
+
+impl<S: AsRef<str>> BeInCidr<S> {
+    /// Parses the block into its network address and prefix length.
+    fn network(&self) -> Option<(IpAddr, u32)> {
+        let (address, prefix) = self.0.as_ref().split_once('/')?;
+        let address: IpAddr = address.parse().ok()?;
+        let prefix: u32 = prefix.parse().ok()?;
+        let bits = if address.is_ipv4() { 32 } else { 128 };
+        (prefix <= bits).then_some((address, prefix))
+    }
+
+    /// Returns `true` if the address belongs to the block.
+    fn contains(&self, address: IpAddr) -> bool {
+        match (self.network(), address) {
+            (Some((IpAddr::V4(network), prefix)), IpAddr::V4(address)) => {
+                let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+                u32::from(network) & mask == u32::from(address) & mask
+            }
+            (Some((IpAddr::V6(network), prefix)), IpAddr::V6(address)) => {
+                let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+                u128::from(network) & mask == u128::from(address) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<S: AsRef<str>> Constraint<str> for BeInCidr<S> {
+    fn validate(&self, value: &str) -> bool {
+        value.parse().is_ok_and(|address| self.contains(address))
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = match self.network() {
+            Some(_) => format!("{}: expected an address in {}", description, self.0.as_ref()),
+            None => format!("{}: invalid CIDR block {:?}", description, self.0.as_ref()),
+        };
+        ConstraintError::new(move || message.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &str, description: String) -> ConstraintError {
+        let message = match self.network() {
+            Some(_) => format!(
+                "{}: expected an address in {}, got {:?}",
+                description,
+                self.0.as_ref(),
+                value
+            ),
+            None => format!("{}: invalid CIDR block {:?}", description, self.0.as_ref()),
+        };
+        ConstraintError::new(move || message.clone())
+            .with_value(value)
+            .with_optional_hint(whitespace_hint(value, |value| self.validate(value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_accept_any_formatted_address(v4: Ipv4Addr, v6: Ipv6Addr) {
+            let (v4, v6) = (v4.to_string(), v6.to_string());
+
+            expect!(BeIpv4.validate(&v4)).to(be_true());
+            expect!(BeIpv6.validate(&v4)).to(be_false());
+            expect!(BeIpv6.validate(&v6)).to(be_true());
+            expect!(BeIpv4.validate(&v6)).to(be_false());
+            expect!(BeIpAddress.validate(&v4) && BeIpAddress.validate(&v6)).to(be_true());
+        }
+
+        #[test]
+        fn should_contain_the_addresses_sharing_the_prefix(address: Ipv4Addr, prefix in 0u32..=32) {
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            let network = Ipv4Addr::from(u32::from(address) & mask);
+            let cidr = BeInCidr(format!("{}/{}", network, prefix));
+
+            expect!(cidr.validate(&address.to_string())).to(be_true());
+        }
+    }
+
+    #[test]
+    fn should_reject_malformed_addresses() {
+        expect!(BeIpv4.validate("256.0.0.1")).to(be_false());
+        expect!(BeIpv4.validate("10.0.0")).to(be_false());
+        expect!(BeIpv6.validate("2001:db8:::1")).to(be_false());
+        expect!(BeIpAddress.validate("localhost")).to(be_false());
+    }
+
+    #[test]
+    fn should_check_membership_in_a_block() {
+        expect!(BeInCidr("10.0.0.0/8").validate("10.255.0.1")).to(be_true());
+        expect!(BeInCidr("10.0.0.0/8").validate("11.0.0.1")).to(be_false());
+        expect!(BeInCidr("0.0.0.0/0").validate("8.8.8.8")).to(be_true());
+        expect!(BeInCidr("2001:db8::/32").validate("2001:db8::1")).to(be_true());
+        expect!(BeInCidr("2001:db8::/32").validate("2001:db9::1")).to(be_false());
+        expect!(BeInCidr("10.0.0.0/8").validate("::ffff:10.0.0.1")).to(be_false());
+    }
+
+    #[test]
+    fn should_reject_every_value_of_an_invalid_block() {
+        let constraint = BeInCidr("10.0.0.0/33");
+
+        let exception = constraint.generate_exception_with_value("10.0.0.1", "Peer".to_string());
+
+        expect!(constraint.validate("10.0.0.1")).to(be_false());
+        expect!(exception.message()).to(be_equal_to(r#"Peer: invalid CIDR block "10.0.0.0/33""#));
+    }
+
+    #[test]
+    fn should_mention_the_block() {
+        let constraint = BeInCidr("10.0.0.0/8");
+
+        let exception = constraint.generate_exception_with_value("11.0.0.1", "Peer".to_string());
+
+        expect!(exception.message())
+            .to(be_equal_to(r#"Peer: expected an address in 10.0.0.0/8, got "11.0.0.1""#));
+    }
+
+    #[test]
+    fn should_hint_at_surrounding_whitespace() {
+        let exception = BeIpv4.generate_exception_with_value(" 10.0.0.1 ", "Peer".to_string());
+
+        expect!(exception.hint())
+            .to(be_some().value("try removing leading and trailing whitespace"));
+        expect!(BeIpv4.generate_exception_with_value("10.0.0", "Peer".into()).hint()).to(be_none());
+    }
+}
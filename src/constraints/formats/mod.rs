@@ -0,0 +1,7 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+pub mod be_hostname;
+pub mod be_ip_address;
+pub mod pass_luhn_check;
@@ -0,0 +1,191 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::constraints::strings::whitespace_hint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// A constraint that checks if a string is a number passing the Luhn checksum.
+///
+/// The Luhn algorithm guards credit card numbers, IMEIs and many national identifiers against
+/// typos. Values must consist of at least two ASCII digits; with `ignore_separators` set, spaces
+/// and dashes are dropped first, so `4539 1488 0343 6467` is accepted as written.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Card number must be valid", |s| {
+///     s.must("4539-1488-0343-6467", PassLuhnCheck::ignoring_separators());
+/// });
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PassLuhnCheck {
+    /// Whether spaces and dashes are removed before checking the value.
+    pub ignore_separators: bool,
+}
+
+impl PassLuhnCheck {
+    /// Creates a constraint that only accepts digits.
+    pub fn new() -> Self {
+        PassLuhnCheck {
+            ignore_separators: false,
+        }
+    }
+
+    /// Creates a constraint that drops spaces and dashes before checking the digits.
+    pub fn ignoring_separators() -> Self {
+        PassLuhnCheck {
+            ignore_separators: true,
+        }
+    }
+
+    /// Suggests how to fix a value that fails the check, if only its separators are in the way.
+    fn hint(&self, value: &str) -> Option<String> {
+        let only_separators = !self.ignore_separators
+            && value.contains([' ', '-'])
+            && Self::ignoring_separators().validate(value);
+        if only_separators {
+            Some("try removing the spaces and dashes".to_string())
+        } else {
+            whitespace_hint(value, |value| self.validate(value))
+        }
+    }
+
+    /// Finds why a value fails the check.
+    ///
+    /// # Returns:
+    /// A description of the problem, or `None` if the value passes the check.
+    fn problem(&self, value: &str) -> Option<String> {
+        let mut digits = Vec::new();
+        for (index, c) in value.chars().enumerate() {
+            match c {
+                '0'..='9' => digits.push(c as u32 - '0' as u32),
+                ' ' | '-' if self.ignore_separators => {}
+                _ => return Some(format!("invalid character {:?} at index {}", c, index)),
+            }
+        }
+        if digits.len() < 2 {
+            return Some("too few digits".to_string());
+        }
+        let sum: u32 = digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(position, digit)| match position % 2 {
+                0 => *digit,
+                _ if *digit > 4 => digit * 2 - 9,
+                _ => digit * 2,
+            })
+            .sum();
+        (!sum.is_multiple_of(10)).then(|| "checksum mismatch".to_string())
+    }
+}
+
+impl Constraint<str> for PassLuhnCheck {
+    fn validate(&self, value: &str) -> bool {
+        self.problem(value).is_none()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: expected a number passing the Luhn check", description);
+        ConstraintError::new(move || message.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &str, description: String) -> ConstraintError {
+        let message = match self.problem(value) {
+            Some(problem) => format!(
+                "{}: expected a number passing the Luhn check, got {:?} ({})",
+                description, value, problem
+            ),
+            None => format!(
+                "{}: expected a number passing the Luhn check, got {:?}",
+                description, value
+            ),
+        };
+        ConstraintError::new(move || message.clone())
+            .with_value(value)
+            .with_optional_hint(self.hint(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    /// Appends the Luhn check digit to the given digits.
+    fn with_check_digit(digits: &str) -> String {
+        (0..10)
+            .map(|check| format!("{}{}", digits, check))
+            .find(|number| PassLuhnCheck::new().validate(number))
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn should_accept_numbers_with_their_check_digit(digits in "[0-9]{1,30}") {
+            expect!(PassLuhnCheck::new().validate(&with_check_digit(&digits))).to(be_true());
+        }
+
+        #[test]
+        fn should_detect_a_single_altered_digit(digits in "[0-9]{1,30}", delta in 1u32..10) {
+            let mut number: Vec<char> = with_check_digit(&digits).chars().collect();
+            let last = number.len() - 1;
+            number[last] = char::from_digit((number[last].to_digit(10).unwrap() + delta) % 10, 10)
+                .unwrap();
+            let number: String = number.into_iter().collect();
+
+            expect!(PassLuhnCheck::new().validate(&number)).to(be_false());
+        }
+    }
+
+    #[test]
+    fn should_accept_known_card_numbers() {
+        expect!(PassLuhnCheck::new().validate("4539148803436467")).to(be_true());
+        expect!(PassLuhnCheck::new().validate("79927398713")).to(be_true());
+        expect!(PassLuhnCheck::new().validate("79927398710")).to(be_false());
+    }
+
+    #[test]
+    fn should_only_ignore_separators_if_configured() {
+        let number = "4539 1488-0343 6467";
+
+        expect!(PassLuhnCheck::new().validate(number)).to(be_false());
+        expect!(PassLuhnCheck::ignoring_separators().validate(number)).to(be_true());
+        expect!(PassLuhnCheck::ignoring_separators().validate("4539_1488")).to(be_false());
+    }
+
+    #[test]
+    fn should_reject_too_short_numbers() {
+        expect!(PassLuhnCheck::new().validate("0")).to(be_false());
+        expect!(PassLuhnCheck::ignoring_separators().validate(" - ")).to(be_false());
+    }
+
+    #[test]
+    fn should_explain_the_failure() {
+        let constraint = PassLuhnCheck::new();
+
+        let mismatch = constraint.generate_exception_with_value("79927398710", "Card".into());
+        let invalid = constraint.generate_exception_with_value("7992 7398", "Card".into());
+
+        expect!(mismatch.message()).to(be_equal_to(
+            r#"Card: expected a number passing the Luhn check, got "79927398710" (checksum mismatch)"#,
+        ));
+        expect!(invalid.message()).to(be_equal_to(
+            r#"Card: expected a number passing the Luhn check, got "7992 7398" (invalid character ' ' at index 4)"#,
+        ));
+    }
+
+    #[test]
+    fn should_hint_at_separators() {
+        let constraint = PassLuhnCheck::new();
+
+        let exception = constraint.generate_exception_with_value("4539-1488-0343-6467", "Card".into());
+
+        expect!(exception.hint()).to(be_some().value("try removing the spaces and dashes"));
+    }
+}
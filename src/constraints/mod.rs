@@ -3,5 +3,12 @@
  * 2-Clause BSD License.
  */
 
+pub mod be_equal_to;
+pub mod cached;
 pub mod collections;
 pub mod constraint;
+pub mod formats;
+pub mod not;
+pub mod numeric;
+pub mod strings;
+pub mod with_exception;
@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+use std::fmt::{Debug, Formatter};
+
+/// A constraint satisfied by the values that do *not* satisfy another constraint.
+///
+/// `s.must(value, not(constraint))` accepts the same values as `s.must_not(value, constraint)`,
+/// but the negation is part of the constraint itself. This makes it possible to store negated
+/// constraints where only `must` is applied, like a registry of rules built at runtime.
+///
+/// Its errors rewrite the message of the wrapped constraint, so that `expected size 3, got 3`
+/// becomes `expected NOT size 3, got 3`.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Roles", |s| s.must(vec!["admin"], not(BeEmpty)));
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Clone)]
+pub struct Not<C>(pub C);
+
+/// Negates a constraint.
+///
+/// # Parameters:
+/// - `constraint`: The constraint that the values must not satisfy.
+///
+/// # Returns:
+/// A `Not` constraint wrapping the given one.
+pub fn not<C>(constraint: C) -> Not<C> {
+    Not(constraint)
+}
+
+impl<T: ?Sized, C: Constraint<T>> Constraint<T> for Not<C> {
+    fn validate(&self, value: &T) -> bool {
+        !self.0.validate(value)
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        negate(self.0.generate_exception(description.clone()), description)
+    }
+
+    fn generate_exception_with_value(&self, value: &T, description: String) -> ConstraintError {
+        negate(
+            self.0.generate_exception_with_value(value, description.clone()),
+            description,
+        )
+    }
+}
+
+/// Rewrites the error of the wrapped constraint, negating the detail that follows the description.
+///
+/// The hint of the error, if any, is dropped, since it suggests how to satisfy the constraint.
+pub(crate) fn negate(error: ConstraintError, description: String) -> ConstraintError {
+    let details = error.clone();
+    ConstraintError::new(move || {
+        let message = error.message();
+        match message.strip_prefix(&description).and_then(|m| m.strip_prefix(": ")) {
+            Some(detail) => match detail.strip_prefix("expected ") {
+                Some(expectation) => format!("{}: expected NOT {}", description, expectation),
+                None => format!("{}: expected NOT: {}", description, detail),
+            },
+            None => format!("{}: expected NOT to satisfy the constraint", description),
+        }
+    })
+    .with_details_of(&details)
+}
+
+impl<C: Debug> Debug for Not<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Not({:?})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::be_empty::{BeEmpty, NotBeEmpty};
+    use crate::constraints::collections::have_size::HaveSize;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_negate_the_wrapped_constraint(value: Vec<u8>, size in 0usize..4) {
+            let constraint = not(HaveSize::with_exact_size(size));
+
+            expect!(constraint.validate(&value)).to(be_equal_to(value.len() != size));
+        }
+    }
+
+    #[test]
+    fn should_negate_the_expectation_of_the_message() {
+        let constraint = not(HaveSize::with_exact_size(2));
+
+        let exception = constraint.generate_exception_with_value(&[1, 2], "Tags".to_string());
+
+        expect!(exception.message()).to(be_equal_to("Tags: expected NOT size 2, got 2"));
+    }
+
+    #[test]
+    fn should_keep_other_details_of_the_message() {
+        let constraint = not(HaveSize::new(|size| size > 1));
+
+        let exception = constraint.generate_exception_with_value(&[1, 2], "Tags".to_string());
+
+        expect!(exception.message()).to(be_equal_to("Tags: expected NOT: unexpected size 2"));
+    }
+
+    #[test]
+    fn should_describe_a_negation_without_details() {
+        let constraint = not(|value: &u8| *value > 1);
+
+        let exception = constraint.generate_exception_with_value(&2, "Value".to_string());
+
+        expect!(exception.message())
+            .to(be_equal_to("Value: expected NOT to satisfy the constraint"));
+    }
+
+    #[test]
+    fn should_drop_the_hint_of_the_wrapped_constraint() {
+        let empty = not(BeEmpty).generate_exception_with_value(&[0u8; 0][..], "Tags".to_string());
+        let filled = not(NotBeEmpty).generate_exception_with_value(&[1][..], "Tags".to_string());
+
+        expect!(empty.hint()).to(be_none());
+        expect!(filled.hint()).to(be_none());
+        expect!(filled.to_string())
+            .to(be_equal_to("Tags: expected NOT collection to not be empty"));
+    }
+
+    #[test]
+    fn should_cancel_a_double_negation() {
+        let constraint = not(not(HaveSize::with_exact_size(1)));
+
+        expect!(constraint.validate(&[1])).to(be_true());
+    }
+}
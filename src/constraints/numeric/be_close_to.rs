@@ -0,0 +1,187 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// How the tolerance of a `BeCloseTo` constraint is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tolerance {
+    /// The difference must be at most `epsilon`.
+    Absolute,
+    /// The difference must be at most `epsilon` times the largest magnitude of both values.
+    Relative,
+}
+
+/// A constraint that checks if a floating point number is approximately equal to an expected one.
+///
+/// Comparing floats with `==` fails on rounding errors, like `0.1 + 0.2 != 0.3`. This is the
+/// Rust counterpart of Kotest's `plusOrMinus`, available for `f32` and `f64`. Its errors show the
+/// actual difference, e.g. `expected 0.3 ± 0.01, got 0.35 (difference 0.04999999999999999)`.
+///
+/// `NaN` is never close to any number, while infinities are only close to themselves.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Total must be 0.3", |s| {
+///     s.must(0.1 + 0.2, BeCloseTo::absolute(0.3, 1e-9));
+/// });
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BeCloseTo<T> {
+    /// The expected value.
+    pub expected: T,
+    /// The largest accepted difference, measured as specified by `tolerance`.
+    pub epsilon: T,
+    /// How `epsilon` is applied.
+    pub tolerance: Tolerance,
+}
+
+impl<T> BeCloseTo<T> {
+    /// Creates a constraint that accepts values at most `epsilon` away from `expected`.
+    pub fn absolute(expected: T, epsilon: T) -> Self {
+        BeCloseTo {
+            expected,
+            epsilon,
+            tolerance: Tolerance::Absolute,
+        }
+    }
+
+    /// Creates a constraint that accepts values whose difference with `expected` is at most
+    /// `epsilon` times the largest magnitude of both, e.g. `0.01` for a 1% tolerance.
+    pub fn relative(expected: T, epsilon: T) -> Self {
+        BeCloseTo {
+            expected,
+            epsilon,
+            tolerance: Tolerance::Relative,
+        }
+    }
+}
+
+/// Implements `Constraint` for `BeCloseTo` on a floating point type.
+macro_rules! impl_be_close_to {
+    ($float:ty) => {
+        impl Constraint<$float> for BeCloseTo<$float> {
+            fn validate(&self, value: &$float) -> bool {
+                if *value == self.expected {
+                    return true;
+                }
+                let difference = (value - self.expected).abs();
+                match self.tolerance {
+                    Tolerance::Absolute => difference <= self.epsilon,
+                    Tolerance::Relative => {
+                        difference <= self.epsilon * value.abs().max(self.expected.abs())
+                    }
+                }
+            }
+
+            fn generate_exception(&self, description: String) -> ConstraintError {
+                let message = format!("{}: expected {}", description, self.describe());
+                ConstraintError::new(move || message.clone())
+            }
+
+            fn generate_exception_with_value(
+                &self,
+                value: &$float,
+                description: String,
+            ) -> ConstraintError {
+                let message = format!(
+                    "{}: expected {}, got {} (difference {})",
+                    description,
+                    self.describe(),
+                    value,
+                    (value - self.expected).abs()
+                );
+                ConstraintError::new(move || message.clone()).with_value(value)
+            }
+        }
+
+        impl BeCloseTo<$float> {
+            /// Describes the accepted values, e.g. `0.3 ± 0.01`.
+            fn describe(&self) -> String {
+                match self.tolerance {
+                    Tolerance::Absolute => format!("{} ± {}", self.expected, self.epsilon),
+                    Tolerance::Relative => format!(
+                        "{} within a relative tolerance of {}",
+                        self.expected, self.epsilon
+                    ),
+                }
+            }
+        }
+    };
+}
+
+impl_be_close_to!(f32);
+impl_be_close_to!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_accept_values_within_the_absolute_tolerance(
+            expected in -1e6f64..1e6,
+            offset in -1.0f64..1.0,
+        ) {
+            let constraint = BeCloseTo::absolute(expected, 1.0);
+
+            expect!(constraint.validate(&(expected + offset))).to(be_true());
+            expect!(constraint.validate(&(expected + 2.0))).to(be_false());
+        }
+
+        #[test]
+        fn should_scale_the_relative_tolerance(expected in 1.0f32..1e6) {
+            let constraint = BeCloseTo::relative(expected, 0.01);
+
+            expect!(constraint.validate(&(expected * 1.005))).to(be_true());
+            expect!(constraint.validate(&(expected * 1.02))).to(be_false());
+        }
+    }
+
+    #[test]
+    fn should_tolerate_rounding_errors() {
+        expect!(BeCloseTo::absolute(0.3, 1e-9).validate(&(0.1 + 0.2))).to(be_true());
+    }
+
+    #[test]
+    fn should_never_accept_nan() {
+        expect!(BeCloseTo::absolute(f64::NAN, 1.0).validate(&f64::NAN)).to(be_false());
+        expect!(BeCloseTo::relative(1.0, f64::INFINITY).validate(&f64::NAN)).to(be_false());
+    }
+
+    #[test]
+    fn should_accept_an_infinity_only_if_expected() {
+        let constraint = BeCloseTo::absolute(f64::INFINITY, 1.0);
+
+        expect!(constraint.validate(&f64::INFINITY)).to(be_true());
+        expect!(constraint.validate(&f64::MAX)).to(be_false());
+    }
+
+    #[test]
+    fn should_show_the_difference() {
+        let constraint = BeCloseTo::absolute(1.0, 0.25);
+
+        let exception = constraint.generate_exception_with_value(&1.5, "Ratio".to_string());
+
+        expect!(exception.message())
+            .to(be_equal_to("Ratio: expected 1 ± 0.25, got 1.5 (difference 0.5)"));
+    }
+
+    #[test]
+    fn should_describe_the_relative_tolerance() {
+        let constraint = BeCloseTo::relative(2.0f32, 0.5);
+
+        let exception = constraint.generate_exception_with_value(&4.0, "Ratio".to_string());
+
+        expect!(exception.message()).to(be_equal_to(
+            "Ratio: expected 2 within a relative tolerance of 0.5, got 4 (difference 2)",
+        ));
+    }
+}
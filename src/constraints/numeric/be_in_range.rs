@@ -0,0 +1,135 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+use std::fmt::{Debug, Display};
+
+/// A constraint that checks if a number lies within inclusive bounds.
+///
+/// Either bound can be left open, which makes this the counterpart of Kotest's `shouldBeInRange`,
+/// `shouldBeGreaterThanOrEqual` and `shouldBeLessThanOrEqual` at once. Its errors show the
+/// accepted range, e.g. `expected a value between 1 and 10, got 12`.
+///
+/// Values that are not comparable with a bound, like `NaN`, are rejected.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Age must be valid", |s| {
+///     s.must(42, BeInRange::between(0, 150));
+///     s.must(0.5, BeInRange::at_least(0.0));
+/// });
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BeInRange<T> {
+    /// The smallest accepted value, if any.
+    pub min: Option<T>,
+    /// The largest accepted value, if any.
+    pub max: Option<T>,
+}
+
+impl<T> BeInRange<T> {
+    /// Creates a constraint that accepts values from `min` to `max`, both inclusive.
+    pub fn between(min: T, max: T) -> Self {
+        BeInRange {
+            min: Some(min),
+            max: Some(max),
+        }
+    }
+
+    /// Creates a constraint that accepts values from `min` on, inclusive.
+    pub fn at_least(min: T) -> Self {
+        BeInRange {
+            min: Some(min),
+            max: None,
+        }
+    }
+
+    /// Creates a constraint that accepts values up to `max`, inclusive.
+    pub fn at_most(max: T) -> Self {
+        BeInRange {
+            min: None,
+            max: Some(max),
+        }
+    }
+}
+
+impl<T: Display> BeInRange<T> {
+    /// Describes the accepted values, e.g. `between 1 and 10`.
+    fn describe(&self) -> String {
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) => format!("between {} and {}", min, max),
+            (Some(min), None) => format!("at least {}", min),
+            (None, Some(max)) => format!("at most {}", max),
+            (None, None) => "of any value".to_string(),
+        }
+    }
+}
+
+impl<T: PartialOrd + Display + Debug> Constraint<T> for BeInRange<T> {
+    fn validate(&self, value: &T) -> bool {
+        self.min.as_ref().is_none_or(|min| value >= min)
+            && self.max.as_ref().is_none_or(|max| value <= max)
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: expected a value {}", description, self.describe());
+        ConstraintError::new(move || message.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &T, description: String) -> ConstraintError {
+        let message = format!(
+            "{}: expected a value {}, got {}",
+            description,
+            self.describe(),
+            value
+        );
+        ConstraintError::new(move || message.clone()).with_value(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_accept_only_values_within_the_bounds(value: i32, a: i32, b: i32) {
+            let (min, max) = if a <= b { (a, b) } else { (b, a) };
+
+            expect!(BeInRange::between(min, max).validate(&value))
+                .to(be_equal_to(min <= value && value <= max));
+            expect!(BeInRange::at_least(min).validate(&value)).to(be_equal_to(min <= value));
+            expect!(BeInRange::at_most(max).validate(&value)).to(be_equal_to(value <= max));
+        }
+    }
+
+    #[test]
+    fn should_reject_values_that_are_not_comparable() {
+        expect!(BeInRange::at_most(1.0).validate(&f64::NAN)).to(be_false());
+    }
+
+    #[test]
+    fn should_describe_the_accepted_range() {
+        let exception =
+            BeInRange::between(1, 10).generate_exception_with_value(&12, "Age".to_string());
+
+        expect!(exception.message()).to(be_equal_to(
+            "Age: expected a value between 1 and 10, got 12",
+        ));
+    }
+
+    #[test]
+    fn should_describe_an_unbounded_range() {
+        let range = BeInRange::<i32> { min: None, max: None };
+
+        expect!(range.generate_exception("Age".to_string()).message())
+            .to(be_equal_to("Age: expected a value of any value"));
+    }
+}
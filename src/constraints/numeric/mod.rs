@@ -0,0 +1,6 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+pub mod be_close_to;
+pub mod be_in_range;
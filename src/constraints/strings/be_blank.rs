@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// A constraint that checks if a string is blank, i.e. empty or made only of whitespace.
+///
+/// Like Kotlin's `isBlank`, it is mostly used negated, to reject values that would look empty
+/// once rendered.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Username must not be blank", |s| s.must_not(" \t", BeBlank));
+/// assert_eq!(
+///     scope.failures()[0].message(),
+///     r#"Username must not be blank: expected NOT a blank string, got " \t""#
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BeBlank;
+
+impl Constraint<str> for BeBlank {
+    fn validate(&self, value: &str) -> bool {
+        value.trim().is_empty()
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: expected a blank string", description);
+        ConstraintError::new(move || message.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &str, description: String) -> ConstraintError {
+        let message = format!("{}: expected a blank string, got {:?}", description, value);
+        ConstraintError::new(move || message.clone()).with_value(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_accept_whitespace_only(value in "[ \t\n]*") {
+            expect!(BeBlank.validate(&value)).to(be_true());
+        }
+
+        #[test]
+        fn should_reject_values_with_other_characters(value in "[ \t]*[a-z][ a-z]*") {
+            expect!(BeBlank.validate(&value)).to(be_false());
+        }
+    }
+
+    #[test]
+    fn should_mention_the_value() {
+        let exception = BeBlank.generate_exception_with_value(" a ", "Name".to_string());
+
+        expect!(exception.message()).to(be_equal_to(r#"Name: expected a blank string, got " a ""#));
+    }
+}
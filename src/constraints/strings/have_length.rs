@@ -0,0 +1,241 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::constraints::strings::whitespace_hint;
+use crate::errors::constraint_error::ConstraintError;
+use std::fmt::Debug;
+use std::sync::Arc;
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The unit in which the length of a string is measured.
+///
+/// More units may be added, and `Graphemes` only exists with the `unicode-segmentation` feature,
+/// so matches on a `LengthUnit` outside this crate need a wildcard arm.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LengthUnit {
+    /// UTF-8 bytes, as returned by `str::len`. Useful for storage limits.
+    Bytes,
+    /// Unicode scalar values, as returned by `str::chars`.
+    #[default]
+    Chars,
+    /// Extended grapheme clusters, i.e. what a reader perceives as a single character, like an
+    /// emoji with a skin tone modifier. Requires the `unicode-segmentation` feature.
+    #[cfg(feature = "unicode-segmentation")]
+    Graphemes,
+}
+
+impl LengthUnit {
+    /// Measures the length of a string in this unit.
+    pub fn measure(&self, value: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => value.len(),
+            LengthUnit::Chars => value.chars().count(),
+            #[cfg(feature = "unicode-segmentation")]
+            LengthUnit::Graphemes => value.graphemes(true).count(),
+        }
+    }
+
+    /// Returns the plural name of this unit, as used in error messages.
+    fn name(&self) -> &'static str {
+        match self {
+            LengthUnit::Bytes => "bytes",
+            LengthUnit::Chars => "characters",
+            #[cfg(feature = "unicode-segmentation")]
+            LengthUnit::Graphemes => "graphemes",
+        }
+    }
+}
+
+/// A constraint that checks the length of a string.
+///
+/// Lengths are measured in `char`s unless another `LengthUnit` is selected with `in_unit`, so
+/// `"héllo"` has a length of 5 even though it takes 6 bytes.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Nickname must be short", |s| {
+///     s.must("héllo", HaveLength::with_max_length(5));
+///     s.must_not("héllo", HaveLength::with_max_length(5).in_unit(LengthUnit::Bytes));
+/// });
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Clone)]
+pub struct HaveLength {
+    predicate: Arc<dyn Fn(usize) -> bool + Send + Sync>,
+    bound: Option<String>,
+    unit: LengthUnit,
+}
+
+impl HaveLength {
+    /// Creates a new `HaveLength` constraint with a custom predicate on the length.
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(usize) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            predicate: Arc::new(predicate),
+            bound: None,
+            unit: LengthUnit::default(),
+        }
+    }
+
+    /// Creates a `HaveLength` constraint for an exact length.
+    pub fn with_exact_length(length: usize) -> Self {
+        Self {
+            bound: Some(length.to_string()),
+            ..Self::new(move |l| l == length)
+        }
+    }
+
+    /// Creates a `HaveLength` constraint accepting lengths up to `length`, inclusive.
+    pub fn with_max_length(length: usize) -> Self {
+        Self {
+            bound: Some(format!("at most {}", length)),
+            ..Self::new(move |l| l <= length)
+        }
+    }
+
+    /// Creates a `HaveLength` constraint accepting lengths from `length` on, inclusive.
+    pub fn with_min_length(length: usize) -> Self {
+        Self {
+            bound: Some(format!("at least {}", length)),
+            ..Self::new(move |l| l >= length)
+        }
+    }
+
+    /// Creates a `HaveLength` constraint accepting lengths from `min` to `max`, both inclusive.
+    pub fn with_length_between(min: usize, max: usize) -> Self {
+        Self {
+            bound: Some(format!("between {} and {}", min, max)),
+            ..Self::new(move |l| (min..=max).contains(&l))
+        }
+    }
+
+    /// Returns a copy of this constraint that measures lengths in the given unit.
+    pub fn in_unit(self, unit: LengthUnit) -> Self {
+        Self { unit, ..self }
+    }
+
+    /// Returns the unit in which this constraint measures lengths.
+    pub fn unit(&self) -> LengthUnit {
+        self.unit
+    }
+}
+
+impl Constraint<str> for HaveLength {
+    fn validate(&self, value: &str) -> bool {
+        (self.predicate)(self.unit.measure(value))
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = match &self.bound {
+            Some(bound) => format!("{}: expected {} {}", description, bound, self.unit.name()),
+            None => description,
+        };
+        ConstraintError::new(move || message.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &str, description: String) -> ConstraintError {
+        let actual = self.unit.measure(value);
+        let unit = self.unit.name();
+        let message = match &self.bound {
+            Some(bound) => format!("{}: expected {} {}, got {}", description, bound, unit, actual),
+            None => format!("{}: unexpected length of {} {}", description, actual, unit),
+        };
+        ConstraintError::new(move || message.clone())
+            .with_value(value)
+            .with_optional_hint(whitespace_hint(value, |value| self.validate(value)))
+    }
+}
+
+impl Debug for HaveLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HaveLength({:?})", self.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_measure_chars_by_default(value: String, length in 0usize..10) {
+            let constraint = HaveLength::with_exact_length(length);
+
+            expect!(constraint.validate(&value)).to(be_equal_to(value.chars().count() == length));
+        }
+
+        #[test]
+        fn should_measure_bytes_if_selected(value: String, length in 0usize..10) {
+            let constraint = HaveLength::with_max_length(length).in_unit(LengthUnit::Bytes);
+
+            expect!(constraint.validate(&value)).to(be_equal_to(value.len() <= length));
+        }
+    }
+
+    #[test]
+    fn should_count_multi_byte_characters_once() {
+        expect!(HaveLength::with_exact_length(5).validate("héllo")).to(be_true());
+        expect!(HaveLength::with_min_length(6).in_unit(LengthUnit::Bytes).validate("héllo"))
+            .to(be_true());
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn should_count_grapheme_clusters_if_selected() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let constraint = HaveLength::with_exact_length(1);
+
+        expect!(constraint.clone().in_unit(LengthUnit::Graphemes).validate(family)).to(be_true());
+        expect!(constraint.validate(family)).to(be_false());
+    }
+
+    #[test]
+    fn should_mention_the_bound_and_unit() {
+        let constraint = HaveLength::with_max_length(3).in_unit(LengthUnit::Bytes);
+
+        let exception = constraint.generate_exception_with_value("héllo", "Name".to_string());
+
+        expect!(exception.message()).to(be_equal_to("Name: expected at most 3 bytes, got 6"));
+    }
+
+    #[test]
+    fn should_mention_both_ends_of_a_range() {
+        let constraint = HaveLength::with_length_between(3, 20);
+
+        let exception = constraint.generate_exception_with_value("ab", "Name".to_string());
+
+        expect!(constraint.validate("abc")).to(be_true());
+        expect!(exception.message())
+            .to(be_equal_to("Name: expected between 3 and 20 characters, got 2"));
+    }
+
+    #[test]
+    fn should_mention_the_length_of_custom_predicates() {
+        let constraint = HaveLength::new(|length| length % 2 == 0);
+
+        let exception = constraint.generate_exception_with_value("abc", "Code".to_string());
+
+        expect!(exception.message()).to(be_equal_to("Code: unexpected length of 3 characters"));
+    }
+
+    #[test]
+    fn should_hint_at_surrounding_whitespace() {
+        let constraint = HaveLength::with_max_length(5);
+
+        let padded = constraint.generate_exception_with_value("admin  ", "Name".to_string());
+        let long = constraint.generate_exception_with_value("administrator", "Name".to_string());
+
+        expect!(padded.hint()).to(be_some().value("try removing trailing whitespace"));
+        expect!(long.hint()).to(be_none());
+    }
+}
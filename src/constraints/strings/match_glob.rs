@@ -0,0 +1,288 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::constraints::strings::whitespace_hint;
+use crate::errors::constraint_error::ConstraintError;
+
+/// A constraint that checks if a string matches a glob pattern, like `logs/**/*.json`.
+///
+/// Globs are the patterns used by shells and `.gitignore` files, which are easier to read than
+/// regular expressions for paths and topic names. The supported syntax is:
+///
+/// - `?` matches a single character other than `/`.
+/// - `*` matches any run of characters other than `/`.
+/// - `**` matches any run of characters, including `/`; `**/` also matches no directory at all,
+///   so `logs/**/*.json` accepts `logs/app.json`.
+/// - `[abc]`, `[a-z]` and `[!a-z]` match a single character in, or not in, the given set.
+/// - `\` escapes the next character, so `\*` matches a literal `*`.
+///
+/// An unterminated `[` and a trailing `\` are matched literally. The whole string must match the
+/// pattern.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// scope.validate_string("Log files must be JSON", |s| {
+///     s.must("logs/2024/10/app.json", MatchGlob::new("logs/**/*.json"));
+/// });
+/// assert!(scope.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchGlob {
+    pattern: String,
+    tokens: Vec<Token>,
+}
+
+/// A compiled element of a glob pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Literal(char),
+    /// `?`
+    AnyChar,
+    /// `*`
+    Star,
+    /// `**` not followed by `/`.
+    AnyPath,
+    /// `**/`
+    AnyDirectories,
+    /// `[...]`, with inclusive ranges of characters.
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl MatchGlob {
+    /// Creates a constraint that accepts the strings matching the given glob pattern.
+    ///
+    /// # Parameters:
+    /// - `pattern`: The glob pattern, e.g. `logs/**/*.json`.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        let tokens = compile(&pattern);
+        MatchGlob { pattern, tokens }
+    }
+
+    /// Returns the pattern of this constraint.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns `true` if the whole value matches the pattern.
+    pub fn is_match(&self, value: &str) -> bool {
+        let value: Vec<char> = value.chars().collect();
+        let mut memo = vec![None; (self.tokens.len() + 1) * (value.len() + 1)];
+        self.matches_from(0, 0, &value, &mut memo)
+    }
+
+    /// Checks if the tokens from `token` on match the characters from `index` on.
+    ///
+    /// Outcomes are memoized by position, which keeps patterns with many stars linear in the
+    /// number of positions instead of exponential.
+    fn matches_from(
+        &self,
+        token: usize,
+        index: usize,
+        value: &[char],
+        memo: &mut [Option<bool>],
+    ) -> bool {
+        let key = token * (value.len() + 1) + index;
+        if let Some(outcome) = memo[key] {
+            return outcome;
+        }
+        let rest = &value[index..];
+        let outcome = match self.tokens.get(token) {
+            None => rest.is_empty(),
+            Some(Token::Literal(expected)) => {
+                rest.first() == Some(expected)
+                    && self.matches_from(token + 1, index + 1, value, memo)
+            }
+            Some(Token::AnyChar) => {
+                rest.first().is_some_and(|c| *c != '/')
+                    && self.matches_from(token + 1, index + 1, value, memo)
+            }
+            Some(Token::Class { negated, ranges }) => {
+                rest.first().is_some_and(|c| {
+                    ranges.iter().any(|(from, to)| (from..=to).contains(&c)) != *negated
+                }) && self.matches_from(token + 1, index + 1, value, memo)
+            }
+            Some(Token::Star) => {
+                let segment = rest.iter().take_while(|c| **c != '/').count();
+                (0..=segment)
+                    .any(|length| self.matches_from(token + 1, index + length, value, memo))
+            }
+            Some(Token::AnyPath) => (0..=rest.len())
+                .any(|length| self.matches_from(token + 1, index + length, value, memo)),
+            Some(Token::AnyDirectories) => {
+                self.matches_from(token + 1, index, value, memo)
+                    || (0..rest.len()).any(|position| {
+                        rest[position] == '/'
+                            && self.matches_from(token + 1, index + position + 1, value, memo)
+                    })
+            }
+        };
+        memo[key] = Some(outcome);
+        outcome
+    }
+}
+
+/// Compiles a glob pattern into its tokens.
+fn compile(pattern: &str) -> Vec<Token> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let (token, length) = match chars[index] {
+            '\\' if index + 1 < chars.len() => (Token::Literal(chars[index + 1]), 2),
+            '?' => (Token::AnyChar, 1),
+            '*' if chars.get(index + 1) == Some(&'*') => match chars.get(index + 2) {
+                Some('/') => (Token::AnyDirectories, 3),
+                _ => (Token::AnyPath, 2),
+            },
+            '*' => (Token::Star, 1),
+            '[' => match compile_class(&chars[index + 1..]) {
+                Some((token, length)) => (token, length + 1),
+                None => (Token::Literal('['), 1),
+            },
+            c => (Token::Literal(c), 1),
+        };
+        tokens.push(token);
+        index += length;
+    }
+    tokens
+}
+
+/// Compiles the body of a character class, following its opening `[`.
+///
+/// # Returns:
+/// The class and the number of characters it spans, including the closing `]`, or `None` if the
+/// class is not terminated.
+fn compile_class(chars: &[char]) -> Option<(Token, usize)> {
+    let negated = chars.first() == Some(&'!');
+    let mut index = usize::from(negated);
+    let mut ranges = Vec::new();
+    // A `]` right after the opening bracket is part of the set, as in shells.
+    while index < chars.len() && (chars[index] != ']' || ranges.is_empty()) {
+        let from = chars[index];
+        match (chars.get(index + 1), chars.get(index + 2)) {
+            (Some('-'), Some(to)) if *to != ']' => {
+                ranges.push((from, *to));
+                index += 3;
+            }
+            _ => {
+                ranges.push((from, from));
+                index += 1;
+            }
+        }
+    }
+    (index < chars.len()).then_some((Token::Class { negated, ranges }, index + 1))
+}
+
+impl Constraint<str> for MatchGlob {
+    fn validate(&self, value: &str) -> bool {
+        self.is_match(value)
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        let message = format!("{}: expected to match {:?}", description, self.pattern);
+        ConstraintError::new(move || message.clone())
+    }
+
+    fn generate_exception_with_value(&self, value: &str, description: String) -> ConstraintError {
+        let message = format!(
+            "{}: expected to match {:?}, got {:?}",
+            description, self.pattern, value
+        );
+        ConstraintError::new(move || message.clone())
+            .with_value(value)
+            .with_optional_hint(whitespace_hint(value, |value| self.is_match(value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    fn matches(pattern: &str, value: &str) -> bool {
+        MatchGlob::new(pattern).is_match(value)
+    }
+
+    proptest! {
+        #[test]
+        fn should_match_itself_if_it_has_no_wildcards(value in "[a-z0-9/._-]{0,20}") {
+            expect!(matches(&value, &value)).to(be_true());
+        }
+
+        #[test]
+        fn should_match_anything_with_a_double_star(value in "\\PC{0,20}") {
+            expect!(matches("**", &value)).to(be_true());
+        }
+
+        #[test]
+        fn should_not_cross_directories_with_a_star(
+            directory in "[a-z]{1,5}",
+            file in "[a-z]{1,5}",
+        ) {
+            let path = format!("{}/{}", directory, file);
+            expect!(matches("*", &path)).to(be_false());
+            expect!(matches("*/*", &path)).to(be_true());
+        }
+    }
+
+    #[test]
+    fn should_match_nested_directories() {
+        expect!(matches("logs/**/*.json", "logs/app.json")).to(be_true());
+        expect!(matches("logs/**/*.json", "logs/2024/10/app.json")).to(be_true());
+        expect!(matches("logs/**/*.json", "logs/2024/app.yaml")).to(be_false());
+        expect!(matches("logs/**/*.json", "audit/app.json")).to(be_false());
+    }
+
+    #[test]
+    fn should_match_single_characters() {
+        expect!(matches("topic.?", "topic.a")).to(be_true());
+        expect!(matches("topic.?", "topic.ab")).to(be_false());
+        expect!(matches("a?b", "a/b")).to(be_false());
+    }
+
+    #[test]
+    fn should_match_character_classes() {
+        expect!(matches("v[0-9].[a-c]", "v1.b")).to(be_true());
+        expect!(matches("v[0-9]", "vx")).to(be_false());
+        expect!(matches("[!a-z]*", "Log")).to(be_true());
+        expect!(matches("[!a-z]*", "log")).to(be_false());
+        expect!(matches("[]]", "]")).to(be_true());
+    }
+
+    #[test]
+    fn should_match_escaped_and_unterminated_characters_literally() {
+        expect!(matches(r"a\*", "a*")).to(be_true());
+        expect!(matches(r"a\*", "ab")).to(be_false());
+        expect!(matches("[abc", "[abc")).to(be_true());
+        expect!(matches("a\\", "a\\")).to(be_true());
+    }
+
+    #[test]
+    fn should_mention_the_pattern() {
+        let constraint = MatchGlob::new("*.json");
+
+        let exception = constraint.generate_exception_with_value("app.yaml", "File".to_string());
+
+        expect!(exception.message()).to(be_equal_to(
+            r#"File: expected to match "*.json", got "app.yaml""#,
+        ));
+    }
+
+    #[test]
+    fn should_hint_at_surrounding_whitespace() {
+        let constraint = MatchGlob::new("*.json");
+
+        let exception = constraint.generate_exception_with_value(" app.json", "File".to_string());
+
+        expect!(exception.hint()).to(be_some().value("try removing leading whitespace"));
+    }
+}
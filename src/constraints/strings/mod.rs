@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+pub mod be_blank;
+pub mod have_length;
+pub mod match_glob;
+
+/// Suggests removing the surrounding whitespace of a string that would be valid without it.
+///
+/// # Parameters:
+/// - `value`: The rejected string.
+/// - `is_valid`: Checks a candidate string against the constraint.
+///
+/// # Returns:
+/// The hint, or `None` if trimming the string does not make it valid.
+pub(crate) fn whitespace_hint(value: &str, is_valid: impl Fn(&str) -> bool) -> Option<String> {
+    let candidates = [
+        (value.trim_end(), "try removing trailing whitespace"),
+        (value.trim_start(), "try removing leading whitespace"),
+        (value.trim(), "try removing leading and trailing whitespace"),
+    ];
+    candidates
+        .into_iter()
+        .find(|(candidate, _)| candidate.len() != value.len() && is_valid(candidate))
+        .map(|(_, hint)| hint.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+
+    #[test]
+    fn should_suggest_the_smallest_trim_that_fixes_the_value() {
+        let is_word = |value: &str| value.chars().all(char::is_alphabetic);
+
+        expect!(whitespace_hint("admin ", is_word))
+            .to(be_some().value("try removing trailing whitespace"));
+        expect!(whitespace_hint(" admin", is_word))
+            .to(be_some().value("try removing leading whitespace"));
+        expect!(whitespace_hint(" admin ", is_word))
+            .to(be_some().value("try removing leading and trailing whitespace"));
+        expect!(whitespace_hint("ad min", is_word)).to(be_none());
+    }
+}
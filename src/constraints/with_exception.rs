@@ -0,0 +1,99 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::constraints::constraint::Constraint;
+use crate::errors::constraint_error::ConstraintError;
+use std::fmt::{Debug, Formatter};
+
+/// A constraint whose errors are created by a custom exception generator.
+///
+/// It checks the same values as the wrapped constraint, but a failure produces the error returned
+/// by the generator, like `RustrictScope::validate_string_with_custom_exception` does for a whole
+/// block. Since the generator travels with the constraint, different rules of the same block can
+/// produce different errors.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// let unique_roles = with_exception(HaveNoDuplicates, |message| {
+///     ConstraintError::new(move || format!("{} are repeated", message))
+/// });
+/// let scope = RustrictScope::new();
+/// scope.validate_string("Roles", |s| s.must(vec!["admin"; 2], unique_roles));
+/// assert_eq!(scope.failures()[0].message(), "Roles are repeated");
+/// ```
+#[derive(Clone)]
+pub struct WithException<C, G> {
+    constraint: C,
+    generator: G,
+}
+
+/// Replaces the errors of a constraint with the ones created by an exception generator.
+///
+/// # Parameters:
+/// - `constraint`: The constraint that checks the values.
+/// - `generator`: A closure that creates the error of a failed rule from its message.
+///
+/// # Returns:
+/// A `WithException` constraint wrapping the given one.
+pub fn with_exception<C, G>(constraint: C, generator: G) -> WithException<C, G>
+where
+    G: Fn(String) -> ConstraintError,
+{
+    WithException {
+        constraint,
+        generator,
+    }
+}
+
+impl<T, C, G> Constraint<T> for WithException<C, G>
+where
+    T: ?Sized,
+    C: Constraint<T>,
+    G: Fn(String) -> ConstraintError,
+{
+    fn validate(&self, value: &T) -> bool {
+        self.constraint.validate(value)
+    }
+
+    fn generate_exception(&self, description: String) -> ConstraintError {
+        (self.generator)(description)
+    }
+}
+
+impl<C: Debug, G> Debug for WithException<C, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WithException({:?})", self.constraint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::have_size::HaveSize;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn should_agree_with_the_wrapped_constraint(value: Vec<u8>, size in 0usize..4) {
+            let constraint = with_exception(HaveSize::with_exact_size(size), |message| {
+                ConstraintError::new(move || message.clone())
+            });
+
+            expect!(constraint.validate(&value)).to(be_equal_to(value.len() == size));
+        }
+    }
+
+    #[test]
+    fn should_create_errors_with_the_generator() {
+        let constraint = with_exception(HaveSize::with_exact_size(1), |message| {
+            ConstraintError::new(move || format!("{} is too long", message))
+        });
+
+        let exception = constraint.generate_exception_with_value(&[1, 2], "Tags".to_string());
+
+        expect!(exception.message()).to(be_equal_to("Tags is too long"));
+    }
+}
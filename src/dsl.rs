@@ -0,0 +1,168 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! The rule names understood by the `constraints!` macro.
+//!
+//! Each rule of the macro, like `username must have_length(3..=20)`, calls the function of this
+//! module with the same name, so the rules read like the infix functions of the Kotlin DSL. Any
+//! other constraint can be used through `satisfy`:
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! let username = "ada";
+//! let roles = vec!["admin"];
+//!
+//! let result = constraints! {
+//!     "Username must be valid" {
+//!         username must not_be_blank;
+//!         username must have_length(3..=20);
+//!     }
+//!     "Roles must be known" {
+//!         roles must satisfy(BeSubsetOf::new(["admin", "viewer"]));
+//!     }
+//! };
+//! assert!(result.is_ok());
+//! ```
+use crate::constraints::be_equal_to::{BeEqualTo, BeOneOf};
+use crate::constraints::collections::be_empty::{BeEmpty, NotBeEmpty};
+use crate::constraints::collections::be_sorted::BeSorted;
+use crate::constraints::collections::be_subset_of::BeSubsetOf;
+use crate::constraints::collections::contain_element::ContainElement;
+use crate::constraints::collections::have_no_duplicates::HaveNoDuplicates;
+use crate::constraints::collections::have_size::HaveSize;
+use crate::constraints::constraint::{AsSubject, Constraint};
+use crate::constraints::not::{not, Not};
+use crate::constraints::strings::be_blank::BeBlank;
+use crate::constraints::strings::have_length::HaveLength;
+use crate::constraints::strings::match_glob::MatchGlob;
+use crate::string_scope::{RuleOutcome, StringScope};
+use std::hash::Hash;
+use std::ops::{RangeFrom, RangeInclusive, RangeToInclusive};
+
+/// Applies a rule of `constraints!` to a borrowed value, so that the value can appear in several
+/// rules without being moved.
+///
+/// # Parameters:
+/// - `scope`: The scope of the block that declares the rule.
+/// - `value`: The checked value.
+/// - `constraint`: The constraint of the rule.
+/// - `expected`: `true` for `must` rules, `false` for `must not` rules.
+#[track_caller]
+pub fn rule<V, T, C>(scope: &StringScope, value: &V, constraint: C, expected: bool) -> RuleOutcome
+where
+    T: ?Sized,
+    V: AsSubject<T>,
+    C: Constraint<T>,
+{
+    let subject: &T = value.as_subject();
+    if expected {
+        scope.must(subject, constraint)
+    } else {
+        scope.must_not(subject, constraint)
+    }
+}
+
+/// Uses any constraint as a rule, e.g. `roles must satisfy(BeSubsetOf::new(["admin"]))`.
+pub fn satisfy<C>(constraint: C) -> C {
+    constraint
+}
+
+/// Checks that a collection is empty.
+pub fn be_empty() -> BeEmpty {
+    BeEmpty
+}
+
+/// Checks that a collection has at least one element.
+pub fn not_be_empty() -> NotBeEmpty {
+    NotBeEmpty
+}
+
+/// Checks that a collection has exactly `size` elements.
+pub fn have_size(size: usize) -> HaveSize {
+    HaveSize::with_exact_size(size)
+}
+
+/// Checks that no element of a collection repeats an earlier one.
+pub fn have_no_duplicates() -> HaveNoDuplicates {
+    HaveNoDuplicates
+}
+
+/// Checks that a collection is sorted in non-decreasing order.
+pub fn be_sorted() -> BeSorted {
+    BeSorted
+}
+
+/// Checks that a collection contains the given element.
+pub fn contain<T>(element: T) -> ContainElement<T> {
+    ContainElement(element)
+}
+
+/// Checks that every element of a collection is one of the allowed ones.
+pub fn be_subset_of<T: Eq + Hash>(allowed: impl IntoIterator<Item = T>) -> BeSubsetOf<T> {
+    BeSubsetOf::new(allowed)
+}
+
+/// Checks that a value is equal to the expected one.
+pub fn be_equal_to<T>(expected: T) -> BeEqualTo<T> {
+    BeEqualTo(expected)
+}
+
+/// Checks that a value is one of the allowed ones.
+pub fn be_one_of<T>(allowed: impl IntoIterator<Item = T>) -> BeOneOf<T> {
+    BeOneOf(allowed.into_iter().collect())
+}
+
+/// Checks that a string is empty or made only of whitespace.
+pub fn be_blank() -> BeBlank {
+    BeBlank
+}
+
+/// Checks that a string has at least one character other than whitespace.
+pub fn not_be_blank() -> Not<BeBlank> {
+    not(BeBlank)
+}
+
+/// Checks the length of a string, in characters.
+///
+/// # Parameters:
+/// - `bound`: An exact length (`8`), an inclusive range (`3..=20`), a minimum (`3..`) or a
+///   maximum (`..=20`).
+pub fn have_length(bound: impl LengthBound) -> HaveLength {
+    bound.into_have_length()
+}
+
+/// Checks that a string matches a glob pattern.
+pub fn match_glob(pattern: impl Into<String>) -> MatchGlob {
+    MatchGlob::new(pattern)
+}
+
+/// A bound on the length of a string, accepted by `have_length`.
+pub trait LengthBound {
+    /// Creates the `HaveLength` constraint for this bound.
+    fn into_have_length(self) -> HaveLength;
+}
+
+impl LengthBound for usize {
+    fn into_have_length(self) -> HaveLength {
+        HaveLength::with_exact_length(self)
+    }
+}
+
+impl LengthBound for RangeInclusive<usize> {
+    fn into_have_length(self) -> HaveLength {
+        HaveLength::with_length_between(*self.start(), *self.end())
+    }
+}
+
+impl LengthBound for RangeFrom<usize> {
+    fn into_have_length(self) -> HaveLength {
+        HaveLength::with_min_length(self.start)
+    }
+}
+
+impl LengthBound for RangeToInclusive<usize> {
+    fn into_have_length(self) -> HaveLength {
+        HaveLength::with_max_length(self.end)
+    }
+}
@@ -36,11 +36,10 @@ use std::sync::Arc;
 ///
 /// # Example Usage:
 /// ```rust
-/// let exception = CollectionConstraintError {
-///     lazy_message: Arc::new(|| "Collection constraint violated".to_string()),
-/// };
+/// # use rustrict::prelude::*;
+/// let exception = CollectionConstraintError::new(|| "Collection constraint violated".to_string());
 ///
-/// println!("{}", exception.lazy_message()); // Prints: "Collection constraint violated"
+/// println!("{}", exception.message()); // Prints: "Collection constraint violated"
 /// ```
 ///
 /// This example demonstrates how to create a `CollectionConstraintError` with a lazily evaluated message.
@@ -74,6 +73,7 @@ impl CollectionConstraintError {
     ///
     /// # Example Usage:
     /// ```rust
+    /// # use rustrict::prelude::*;
     /// let error = CollectionConstraintError::new(|| "Collection constraint violated".to_string());
     /// ```
     ///
@@ -100,6 +100,7 @@ impl CollectionConstraintError {
     ///
     /// # Example Usage:
     /// ```rust
+    /// # use rustrict::prelude::*;
     /// let error = CollectionConstraintError::new(|| "Collection constraint violated".to_string());
     /// println!("{}", error.message()); // Prints: "Collection constraint violated"
     /// ```
@@ -133,6 +134,7 @@ impl fmt::Display for CollectionConstraintError {
     ///
     /// # Example Usage:
     /// ```rust
+    /// # use rustrict::prelude::*;
     /// let error = CollectionConstraintError::new(|| "Collection constraint violated".to_string());
     /// println!("{}", error); // Automatically uses the Display implementation
     /// ```
@@ -167,6 +169,7 @@ impl fmt::Debug for CollectionConstraintError {
     ///
     /// # Example Usage:
     /// ```rust
+    /// # use rustrict::prelude::*;
     /// let error = CollectionConstraintError::new(|| "Collection constraint violated".to_string());
     /// println!("{:?}", error); // Automatically uses the Debug implementation
     /// ```
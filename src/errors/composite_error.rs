@@ -2,7 +2,7 @@
  * Copyright (c) 2024, Ignacio Slater M.
  * 2-Clause BSD License.
  */
-use expectest::core::{Join, Matcher};
+use crate::errors::failure_format::FailureFormat;
 use std::error::Error;
 use std::sync::Arc;
 
@@ -30,6 +30,8 @@ use std::sync::Arc;
 ///
 /// ## Example:
 /// ```rust
+/// # use rustrict::prelude::*;
+/// # use std::sync::Arc;
 /// let error1 = Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "Error 1"));
 /// let error2 = Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "Error 2"));
 /// let composite = CompositeError::new(vec![error1, error2]);
@@ -38,8 +40,10 @@ use std::sync::Arc;
 /// In this example, `CompositeError` holds two `std::io::Error` instances, allowing them
 /// to be treated as a single error entity.
 #[derive(Debug)]
-struct CompositeError {
+pub struct CompositeError {
     errors: Vec<Arc<dyn Error + Send + Sync>>,
+    /// The concrete type name of each error, captured before the type was erased.
+    type_names: Vec<&'static str>,
 }
 
 impl CompositeError {
@@ -49,6 +53,10 @@ impl CompositeError {
     /// pointer. Each error must implement the `Error`, `Send`, and `Sync` traits, ensuring that
     /// they are safely shareable across threads.
     ///
+    /// The type name of the errors is taken from `E`, so errors that were already erased into
+    /// `dyn Error + Send + Sync` can't be told apart; use `with_error` to aggregate errors of
+    /// different types instead.
+    ///
     /// # Panics
     /// This function will panic if the provided list of errors is empty, as a `CompositeError`
     /// must contain at least one error. This is similar to requiring a non-empty collection
@@ -56,6 +64,8 @@ impl CompositeError {
     ///
     /// # Example
     /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # use std::sync::Arc;
     /// let error1 = Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "Error 1"));
     /// let error2 = Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "Error 2"));
     /// let composite = CompositeError::new(vec![error1, error2]);
@@ -63,10 +73,56 @@ impl CompositeError {
     ///
     /// In this example, `CompositeError` is constructed with two errors, aggregated into
     /// a single error entity.
-    pub fn new(errors: Vec<Arc<dyn Error + Send + Sync>>) -> Self {
+    pub fn new<E: Cause + ?Sized>(errors: Vec<Arc<E>>) -> Self {
         assert!(!errors.is_empty(), "The list of errors cannot be empty");
 
-        CompositeError { errors }
+        CompositeError {
+            type_names: vec![std::any::type_name::<E>(); errors.len()],
+            errors: errors.into_iter().map(Cause::into_cause).collect(),
+        }
+    }
+
+    /// Creates a new `CompositeError` from errors of a single concrete type.
+    ///
+    /// Unlike `new`, the errors don't need to be wrapped in an `Arc`.
+    ///
+    /// # Panics
+    /// This function will panic if the provided list of errors is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let composite = CompositeError::from_errors(vec![std::fmt::Error]);
+    /// assert_eq!(composite.type_names(), ["core::fmt::Error"]);
+    /// ```
+    pub fn from_errors<E: Error + Send + Sync + 'static>(errors: Vec<E>) -> Self {
+        assert!(!errors.is_empty(), "The list of errors cannot be empty");
+
+        CompositeError {
+            type_names: vec![std::any::type_name::<E>(); errors.len()],
+            errors: errors
+                .into_iter()
+                .map(|error| Arc::new(error) as Arc<dyn Error + Send + Sync>)
+                .collect(),
+        }
+    }
+
+    /// Appends an error of any type to this `CompositeError`, keeping its type name.
+    ///
+    /// This is how errors of different types are aggregated, since `new` and `from_errors` name
+    /// every error after a single type.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let composite = CompositeError::from_errors(vec![std::fmt::Error])
+    ///     .with_error(std::io::Error::other("Error 1"));
+    /// assert_eq!(composite.type_names(), ["core::fmt::Error", "std::io::error::Error"]);
+    /// ```
+    pub fn with_error<E: Error + Send + Sync + 'static>(mut self, error: E) -> Self {
+        self.errors.push(Arc::new(error));
+        self.type_names.push(std::any::type_name::<E>());
+        self
     }
 
     /// Returns a reference to the list of errors contained within this `CompositeError`.
@@ -77,6 +133,8 @@ impl CompositeError {
     ///
     /// # Example
     /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # use std::sync::Arc;
     /// let composite = CompositeError::new(vec![Arc::new(std::io::Error::new(
     ///     std::io::ErrorKind::Other, "Error 1"))]);
     /// for error in composite.errors() {
@@ -89,6 +147,58 @@ impl CompositeError {
     pub fn errors(&self) -> &Vec<Arc<dyn std::error::Error + Send + Sync>> {
         &self.errors
     }
+
+    /// Returns the concrete type name of each error, in the same order as `errors`.
+    ///
+    /// The names are captured when the errors are added, before their type is erased.
+    pub fn type_names(&self) -> &[&'static str] {
+        &self.type_names
+    }
+
+    /// Writes the header and one line per cause, indented by `indent` spaces, recursing into
+    /// nested `CompositeError`s.
+    fn write_tree(&self, f: &mut std::fmt::Formatter<'_>, indent: usize) -> std::fmt::Result {
+        match self.errors.len() {
+            1 => write!(f, "An error occurred:")?,
+            count => write!(f, "{} errors occurred:", count)?,
+        }
+        let padding = " ".repeat(indent + 2);
+        for (error, type_name) in self.errors.iter().zip(&self.type_names) {
+            write!(f, "\n{}- [{}] ", padding, type_name)?;
+            match error.downcast_ref::<CompositeError>() {
+                Some(nested) => nested.write_tree(f, indent + 4)?,
+                None => {
+                    let continuation = format!("\n{}  ", padding);
+                    write!(f, "{}", error.to_string().replace('\n', &continuation))?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats the contained errors with the given options.
+    ///
+    /// Unlike `Display`, which always uses the same layout, this lets the caller pick a compact
+    /// line for logs or an indented, optionally colored, list for command line tools.
+    ///
+    /// # Parameters:
+    /// - `format`: The options used to present the errors.
+    ///
+    /// # Returns:
+    /// The formatted errors, in the order they were aggregated.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let scope = RustrictScope::new();
+    /// scope.validate_string("Roles must not be empty", |s| s.must(Vec::<u8>::new(), NotBeEmpty));
+    /// let error = scope.into_result().unwrap_err();
+    ///
+    /// println!("{}", error.format_with(&FailureFormat::multi_line().with_color(true)));
+    /// ```
+    pub fn format_with(&self, format: &FailureFormat) -> String {
+        format.render(&self.errors)
+    }
 }
 
 impl std::fmt::Display for CompositeError {
@@ -97,50 +207,55 @@ impl std::fmt::Display for CompositeError {
     ///
     /// This implementation allows `CompositeError` to be formatted as a string, similar to how you
     /// might override `toString()` in Kotlin to customize the string representation of an object.
-    /// The formatted string will describe either a single error or a collection of errors in a
-    /// human-readable format.
+    /// The errors are rendered as a tree, so that many failures stay readable.
     ///
     /// # Formatting Behavior:
-    /// - If `CompositeError` contains a single error, the output will be formatted as:
-    ///   `"An error occurred -- [ErrorType] ErrorMessage"`.
-    /// - If `CompositeError` contains multiple errors, the output will be formatted as:
-    ///   `"Multiple errors occurred -- { [ErrorType] ErrorMessage },\n{ [ErrorType] ErrorMessage }, ..."`.
+    /// - The first line is a header with the number of errors, e.g. `"2 errors occurred:"`.
+    /// - Each error follows on its own line, indented and prefixed by its concrete type:
+    ///   `"  - [ErrorType] ErrorMessage"`. The continuation lines of a multi-line message are
+    ///   aligned with its first line.
+    /// - A nested `CompositeError` is rendered the same way, one level deeper.
+    ///
+    /// Use `format_with` for a single line, or to leave the type names out.
     ///
     /// # Example:
     /// ```rust
-    /// let error1 = Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "Error 1"));
-    /// let error2 = Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "Error 2"));
-    /// let composite = CompositeError::new(vec![error1, error2]);
-    /// println!("{}", composite);
-    /// ```
+    /// # use rustrict::prelude::*;
+    /// # use std::sync::Arc;
+    /// let nested = CompositeError::from_errors(vec![std::fmt::Error]);
+    /// let io_error = std::io::Error::new(std::io::ErrorKind::Other, "Error 1");
+    /// let composite = CompositeError::new(vec![Arc::new(io_error)]).with_error(nested);
     ///
-    /// In this example, `CompositeError` will be displayed as a string listing each error,
-    /// helping to quickly identify all underlying issues.
+    /// assert_eq!(
+    ///     composite.to_string(),
+    ///     "2 errors occurred:\n  - [std::io::error::Error] Error 1\n  \
+    ///      - [rustrict::errors::composite_error::CompositeError] An error occurred:\n      \
+    ///      - [core::fmt::Error] an error occurred when formatting an argument"
+    /// );
+    /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let message = if self.errors.len() == 1 {
-            format!(
-                "An error occurred -- [{}] {}",
-                std::any::type_name::<dyn Error>(),
-                self.errors[0].to_string()
-            )
-        } else {
-            let error_messages = self
-                .errors
-                .iter()
-                .map(|e: &Arc<dyn Error + Send + Sync>| {
-                    format!(
-                        "{{ [{}] {} }}",
-                        std::any::type_name::<dyn Error>(),
-                        e.to_string()
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(",\n");
-
-            format!("Multiple errors occurred -- {}", error_messages)
-        };
-
-        write!(f, "{}", message)
+        self.write_tree(f, 0)
+    }
+}
+
+/// An error that can be aggregated by `CompositeError::new`.
+///
+/// It is implemented for every error type, and for `dyn Error + Send + Sync` so that errors that
+/// were already erased can be aggregated as well.
+pub trait Cause: Error + Send + Sync + 'static {
+    /// Erases the concrete type of the error.
+    fn into_cause(self: Arc<Self>) -> Arc<dyn Error + Send + Sync>;
+}
+
+impl<E: Error + Send + Sync + 'static> Cause for E {
+    fn into_cause(self: Arc<Self>) -> Arc<dyn Error + Send + Sync> {
+        self
+    }
+}
+
+impl Cause for dyn Error + Send + Sync {
+    fn into_cause(self: Arc<Self>) -> Arc<dyn Error + Send + Sync> {
+        self
     }
 }
 
@@ -157,6 +272,8 @@ impl std::fmt::Display for CompositeError {
 ///
 /// # Example:
 /// ```rust
+/// # use rustrict::prelude::*;
+/// # use std::sync::Arc;
 /// let error1 = Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "Error 1"));
 /// let error2 = Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "Error 2"));
 /// let composite = CompositeError::new(vec![error1, error2]);
@@ -172,12 +289,12 @@ impl std::error::Error for CompositeError {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use core::panic;
+    use crate::errors::constraint_error::ConstraintError;
+    use crate::testing::{contain, panic_with_type};
     use expectest::prelude::*;
     use proptest::collection::vec;
     use proptest::prelude::*;
     use std::error::Error;
-    use std::panic::catch_unwind;
     use std::sync::Arc;
 
     /// Tests that a `CompositeError` can be successfully created with multiple errors and that
@@ -245,7 +362,7 @@ mod tests {
             let exceptions: Vec<Arc<dyn Error + Send + Sync>> = messages
                 .into_iter()
                 .map(|msg|
-                    Arc::new(std::io::Error::new(std::io::ErrorKind::Other, msg))
+                    Arc::new(std::io::Error::other(msg))
                         as Arc<dyn Error + Send + Sync>
                 ).collect();
 
@@ -268,7 +385,7 @@ mod tests {
         proptest!(
             |(message in "[a-zA-Z0-9]{1,50}")| {
                 let exception: Arc<dyn Error + Send + Sync> =
-                    Arc::new(std::io::Error::new(std::io::ErrorKind::Other, message.clone()));
+                    Arc::new(std::io::Error::other(message.clone()));
 
                 let composite = CompositeError::new(vec![exception.clone()]);
 
@@ -287,119 +404,64 @@ mod tests {
         );
     }
 
-    /// A custom matcher to check if a panic of a specific type occurs.
-    fn panic_with_type<F, T>(f: F)
-    where
-        F: FnOnce() + panic::UnwindSafe,
-        T: 'static + std::fmt::Debug,
-    {
-        let result = catch_unwind(f);
-        match result {
-            Ok(_) => expect!(false).to(be_true()), // Expecting a panic, but none occurred
-            Err(err) => expect!(err.is::<T>()).to(be_true()), // Expecting a panic of type T
-        };
+    #[test]
+    fn composite_error_can_be_formatted_with_options() {
+        let composite = CompositeError::new(vec![
+            Arc::new(std::io::Error::other("Error 1")),
+            Arc::new(std::io::Error::other("Error 2")),
+        ]);
+
+        expect!(composite.format_with(&FailureFormat::compact()))
+            .to(be_equal_to("2 errors occurred: Error 1; Error 2"));
+        expect!(composite.format_with(&FailureFormat::multi_line()))
+            .to(be_equal_to("2 errors occurred:\n  - Error 1\n  - Error 2"));
     }
 
     #[test]
-    fn composite_error_should_throw_when_empty() {
-        panic_with_type::<_, &'static str>(|| {
-            CompositeError::new(vec![]).errors();
-        });
+    fn composite_error_should_display_a_tree_of_typed_causes() {
+        let nested = CompositeError::from_errors(vec![
+            ConstraintError::new(|| "Roles must not be empty".to_string()),
+            ConstraintError::new(|| "Tags must be unique:\nduplicated a".to_string()),
+        ]);
+        let io_error = std::io::Error::other("Error 1");
+        let composite = CompositeError::new(vec![Arc::new(io_error)]).with_error(nested);
+
+        expect!(composite.to_string()).to(be_equal_to(
+            "2 errors occurred:\n  \
+             - [std::io::error::Error] Error 1\n  \
+             - [rustrict::errors::composite_error::CompositeError] 2 errors occurred:\n      \
+             - [rustrict::errors::constraint_error::ConstraintError] Roles must not be empty\n      \
+             - [rustrict::errors::constraint_error::ConstraintError] Tags must be unique:\n        \
+             duplicated a",
+        ));
     }
-}
 
-/// A struct representing a value to be checked for containment within another value.
-///
-/// In Rust, `Contains<T>` is a generic struct that stores a value of type `T`. This value
-/// can then be used to check if it is contained within another value, such as a `String`.
-/// This concept is similar to creating a custom matcher in Kotlin for checking if a string
-/// contains a substring.
-///
-/// # Type Parameters:
-/// - `T`: The type of the value that you want to check for containment. For example, if you
-///   want to check if a `String` contains another `String`, `T` would be `String`.
-///
-/// # Example:
-/// ```rust
-/// let contains_hello = contain("Hello".to_string());
-/// assert!(contains_hello.matches(&"Hello, world!".to_string()));
-/// ```
-///
-/// In this example, `contains_hello` is a `Contains<String>` instance that checks if a given
-/// `String` contains the word "Hello".
-struct Contains<T> {
-    value: T,
-}
+    #[derive(Debug)]
+    struct UserError;
 
-/// A function to create a `Contains<T>` instance.
-///
-/// This function serves as a constructor for `Contains<T>`, making it easy to create
-/// instances of `Contains` without having to explicitly write out the struct syntax.
-/// It's similar to factory functions you might define in Kotlin.
-///
-/// # Parameters:
-/// - `value`: The value that you want to check for containment. This value is stored
-///   inside the `Contains` struct.
-///
-/// # Returns:
-/// - A `Contains<T>` instance that holds the provided value.
-///
-/// # Example:
-/// ```rust
-/// let contains_rust = contain("Rust".to_string());
-/// assert!(contains_rust.matches(&"Learning Rust is fun!".to_string()));
-/// ```
-///
-/// This example shows how to create a `Contains<String>` instance using the `contain`
-/// function and then use it to check if a `String` contains the word "Rust".
-fn contain<T>(value: T) -> Contains<T> {
-    Contains { value }
-}
-
-/// Implementation of the `Matcher` trait for `Contains<String>`.
-///
-/// This implementation allows `Contains<String>` to be used as a matcher in Rust's
-/// testing framework. The `Matcher` trait defines methods for checking if a condition
-/// is met (`matches`) and for generating failure messages (`failure_message`).
-/// This is conceptually similar to writing custom assertions or matchers in Kotlin.
-///
-/// # Methods:
-///
-/// - `failure_message(&self, join: Join, actual: &String) -> String`:
-///   Generates a detailed failure message if the `actual` string does not contain
-///   the expected value. This method is useful for providing clear feedback in tests
-///   when an assertion fails. It works similarly to custom failure messages you might
-///   write in Kotlin testing frameworks.
-///
-/// - `matches(&self, actual: &String) -> bool`:
-///   Checks if the `actual` string contains the expected value stored in `self.value`.
-///   This method returns `true` if the value is found, and `false` otherwise. It's
-///   analogous to using `contains` or similar methods in Kotlin to check for substrings.
-///
-/// # Example:
-/// ```rust
-/// let matcher = contain("Rust".to_string());
-/// assert!(matcher.matches(&"Learning Rust is fun!".to_string()));
-/// ```
-///
-/// In this example, `matches` will return `true` because the string "Rust" is contained
-/// within the given `actual` string.
-impl Matcher<String, String> for Contains<String> {
-    fn failure_message(&self, join: Join, actual: &String) -> String {
-        if join.is_assertion() {
-            format!(
-                "expected {} contain <{:?}>, at index <{:?}>. Got <{:?}>",
-                join,
-                self.value,
-                actual.find(&self.value),
-                actual
-            )
-        } else {
-            format!("expected {} contain <{:?}>", join, self.value)
+    impl std::fmt::Display for UserError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "User error")
         }
     }
 
-    fn matches(&self, actual: &String) -> bool {
-        actual.contains(&self.value)
+    impl Error for UserError {}
+
+    #[test]
+    fn composite_error_should_name_the_types_of_user_errors() {
+        let composite = CompositeError::new(vec![Arc::new(UserError)]).with_error(std::fmt::Error);
+
+        expect!(composite.type_names()).to(be_equal_to(
+            ["rustrict::errors::composite_error::tests::UserError", "core::fmt::Error"].as_slice(),
+        ));
+        expect!(composite.to_string())
+            .to(contain("[rustrict::errors::composite_error::tests::UserError] User error".to_string()));
+    }
+
+    #[test]
+    fn composite_error_should_throw_when_empty() {
+        panic_with_type::<_, &'static str>(|| {
+            CompositeError::new(Vec::<Arc<dyn Error + Send + Sync>>::new()).errors();
+        });
     }
 }
@@ -2,6 +2,10 @@
  * Copyright (c) 2024, Ignacio Slater M.
  * 2-Clause BSD License.
  */
+use crate::errors::failure_record::FailureRecord;
+use std::backtrace::Backtrace;
+use std::fmt::Debug;
+use std::panic::Location;
 use std::sync::Arc;
 
 /// A struct representing a constraint-related error with a lazily evaluated message.
@@ -18,14 +22,31 @@ use std::sync::Arc;
 /// - **Trait Implementations:** `ConstraintError` implements `Display`, `Debug`, `Error`,
 ///     and `Clone`, allowing it to be used effectively within Rust's error handling ecosystem,
 ///     similar to how exceptions might be used in Kotlin.
+/// - **Backtraces:** With the `backtrace` feature enabled, every `ConstraintError` captures the
+///   stack at the point where it was created, much like a Kotlin exception's stack trace.
+/// - **Source Location:** Errors recorded by a validation scope remember the file and line of the
+///   rule that produced them.
+/// - **Thread Safety:** `ConstraintError` is `Send + Sync`, so validation results can be moved
+///   across threads, stored in shared state or returned from async handlers.
+/// - **Failure Details:** Errors can record the rule that failed, the path of the offending field
+///   and a `Debug` snapshot of its value, all exported by `ConstraintError::record`.
+/// - **Hints:** Errors can carry a suggested fix, like `allowed values: "admin", "viewer"`, which
+///   is appended to the `Display` output.
 ///
 /// # Example:
 /// ```rust
+/// # use rustrict::prelude::*;
 /// let error = ConstraintError::new(|| "This is a lazily evaluated error message.".to_string());
 /// println!("{}", error);  // The message is evaluated and printed here.
 /// ```
-pub(crate) struct ConstraintError {
-    lazy_message: Arc<dyn Fn() -> String>,
+pub struct ConstraintError {
+    lazy_message: Arc<dyn Fn() -> String + Send + Sync>,
+    backtrace: Option<Arc<Backtrace>>,
+    location: Option<&'static Location<'static>>,
+    rule: Option<&'static str>,
+    field: Option<Arc<str>>,
+    value: Option<Arc<str>>,
+    hint: Option<Arc<str>>,
 }
 
 impl ConstraintError {
@@ -39,19 +60,28 @@ impl ConstraintError {
     /// - `lazy_message`: A closure that returns a `String`. The closure is evaluated when the error
     ///     message is needed.
     ///
+    /// When the `backtrace` feature is enabled, the current stack is captured as well.
+    ///
     /// # Returns:
     /// A `ConstraintError` instance containing the provided closure.
     ///
     /// # Example:
     /// ```rust
+    /// # use rustrict::prelude::*;
     /// let error = ConstraintError::new(|| "This is a custom error message.".to_string());
     /// ```
-    pub(crate) fn new<F>(lazy_message: F) -> Self
+    pub fn new<F>(lazy_message: F) -> Self
     where
-        F: Fn() -> String + 'static,
+        F: Fn() -> String + Send + Sync + 'static,
     {
         ConstraintError {
             lazy_message: Arc::new(lazy_message),
+            backtrace: capture_backtrace(),
+            location: None,
+            rule: None,
+            field: None,
+            value: None,
+            hint: None,
         }
     }
 
@@ -66,12 +96,193 @@ impl ConstraintError {
     ///
     /// # Example:
     /// ```rust
+    /// # use rustrict::prelude::*;
     /// let error = ConstraintError::new(|| "Delayed message".to_string());
     /// assert_eq!(error.message(), "Delayed message");
     /// ```
-    pub(crate) fn message(&self) -> String {
+    pub fn message(&self) -> String {
         (self.lazy_message)()
     }
+
+    /// Returns the backtrace captured when the error was created.
+    ///
+    /// Backtraces are only captured when the `backtrace` feature is enabled; otherwise this always
+    /// returns `None`. Clones of an error share the same backtrace.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let error = ConstraintError::new(|| "Delayed message".to_string());
+    /// if let Some(backtrace) = error.backtrace() {
+    ///     println!("{}", backtrace);
+    /// }
+    /// ```
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_deref()
+    }
+
+    /// Returns a copy of this error that records the given source location.
+    ///
+    /// Validation scopes use this to attach the location of the rule that failed.
+    ///
+    /// # Parameters:
+    /// - `location`: The location of the call that produced the error.
+    ///
+    /// # Returns:
+    /// The same `ConstraintError`, with its location set.
+    pub fn with_location(mut self, location: &'static Location<'static>) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Returns the source location of the rule that produced this error, if known.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let error = ConstraintError::new(|| "message".to_string());
+    /// if let Some(location) = error.location() {
+    ///     println!("Failed at {}:{}", location.file(), location.line());
+    /// }
+    /// ```
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+
+    /// Returns a copy of this error that records the name of the rule that failed.
+    ///
+    /// Validation scopes use this to attach the type name of the failed constraint.
+    pub fn with_rule(mut self, rule: &'static str) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    /// Returns the name of the rule that produced this error, if known.
+    pub fn rule(&self) -> Option<&'static str> {
+        self.rule
+    }
+
+    /// Returns a copy of this error that records the path of the field that failed.
+    ///
+    /// # Parameters:
+    /// - `field`: The path of the field, e.g. `/user/email` or `user.email`.
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into().into());
+        self
+    }
+
+    /// Returns the path of the field that produced this error, if known.
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// Returns a copy of this error that records a snapshot of the offending value.
+    ///
+    /// The value is formatted with `Debug` right away, so the error does not borrow it.
+    ///
+    /// # Parameters:
+    /// - `value`: The value that failed the rule.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let error = ConstraintError::new(|| "Roles must be unique".to_string())
+    ///     .with_value(&["admin", "admin"]);
+    /// assert_eq!(error.value(), Some(r#"["admin", "admin"]"#));
+    /// ```
+    pub fn with_value<T: Debug + ?Sized>(mut self, value: &T) -> Self {
+        self.value = Some(format!("{:?}", value).into());
+        self
+    }
+
+    /// Returns the `Debug` snapshot of the value that produced this error, if known.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Returns a copy of this error with a suggestion on how to fix the offending value.
+    ///
+    /// Built-in constraints attach hints on their own when they can tell what went wrong.
+    ///
+    /// # Parameters:
+    /// - `hint`: The suggested fix, e.g. `try removing trailing whitespace`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let error = ConstraintError::new(|| "Role must be known".to_string())
+    ///     .with_hint("allowed values: admin, viewer");
+    /// assert_eq!(error.hint(), Some("allowed values: admin, viewer"));
+    /// assert_eq!(error.to_string(), "Role must be known (hint: allowed values: admin, viewer)");
+    /// ```
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into().into());
+        self
+    }
+
+    /// Returns a copy of this error with the given hint, if there is one.
+    pub(crate) fn with_optional_hint(self, hint: Option<String>) -> Self {
+        match hint {
+            Some(hint) => self.with_hint(hint),
+            None => self,
+        }
+    }
+
+    /// Returns the suggested fix for the value that produced this error, if any.
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    /// Returns a copy of this error with the rule, field and value recorded by another error.
+    ///
+    /// Used by constraints that rewrite the errors of the constraints they wrap. The hint is not
+    /// copied, since it rarely holds for the rewritten error.
+    pub(crate) fn with_details_of(mut self, other: &ConstraintError) -> Self {
+        self.rule = other.rule;
+        self.field = other.field.clone();
+        self.value = other.value.clone();
+        self
+    }
+
+    /// Renders this error into a `FailureRecord`.
+    ///
+    /// The message is evaluated once, so the record can be stored or sent to an audit log without
+    /// keeping any closure alive.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let error = ConstraintError::new(|| "Email must not be empty".to_string())
+    ///     .with_field("user.email");
+    ///
+    /// let record = error.record();
+    /// assert_eq!(record.message(), "Email must not be empty");
+    /// assert_eq!(record.field(), Some("user.email"));
+    /// ```
+    pub fn record(&self) -> FailureRecord {
+        let record = FailureRecord::new(
+            self.rule.map(str::to_string),
+            self.message(),
+            self.field.as_deref().map(str::to_string),
+            self.value.as_deref().map(str::to_string),
+        );
+        match &self.hint {
+            Some(hint) => record.with_hint(hint.to_string()),
+            None => record,
+        }
+    }
+}
+
+/// Captures the current stack if the `backtrace` feature is enabled.
+#[cfg(feature = "backtrace")]
+fn capture_backtrace() -> Option<Arc<Backtrace>> {
+    Some(Arc::new(Backtrace::force_capture()))
+}
+
+/// Captures the current stack if the `backtrace` feature is enabled.
+#[cfg(not(feature = "backtrace"))]
+fn capture_backtrace() -> Option<Arc<Backtrace>> {
+    None
 }
 
 impl std::fmt::Display for ConstraintError {
@@ -79,7 +290,8 @@ impl std::fmt::Display for ConstraintError {
     ///
     /// This method implements the `Display` trait, which is used to generate a user-friendly
     /// string representation of the `ConstraintError`. It is akin to overriding the `toString()`
-    /// method in Kotlin for custom exceptions.
+    /// method in Kotlin for custom exceptions. The hint, if any, follows the message as
+    /// `(hint: ...)`; use `message` to get the message alone.
     ///
     /// # Parameters:
     /// - `f`: A mutable reference to a `Formatter`, which handles the formatting.
@@ -87,7 +299,10 @@ impl std::fmt::Display for ConstraintError {
     /// # Returns:
     /// A `Result` indicating success or failure of the formatting operation.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message())
+        match &self.hint {
+            Some(hint) => write!(f, "{} (hint: {})", self.message(), hint),
+            None => write!(f, "{}", self.message()),
+        }
     }
 }
 
@@ -96,7 +311,8 @@ impl std::fmt::Debug for ConstraintError {
     ///
     /// This method implements the `Debug` trait, which is used for debugging output.
     /// The closure is represented as `"<closure>"` in the debug output, since closures
-    /// cannot be easily displayed.
+    /// cannot be easily displayed. The source location and captured backtrace, if any, are
+    /// included as well.
     ///
     /// # Parameters:
     /// - `f`: A mutable reference to a `Formatter`, which handles the formatting.
@@ -104,16 +320,36 @@ impl std::fmt::Debug for ConstraintError {
     /// # Returns:
     /// A `Result` indicating success or failure of the formatting operation.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConstraintError")
-            .field("lazy_message", &"<closure>")
-            .finish()
+        let mut debug = f.debug_struct("ConstraintError");
+        debug.field("lazy_message", &"<closure>");
+        if let Some(location) = self.location {
+            debug.field("location", location);
+        }
+        if let Some(rule) = self.rule {
+            debug.field("rule", &rule);
+        }
+        if let Some(field) = &self.field {
+            debug.field("field", field);
+        }
+        if let Some(value) = &self.value {
+            debug.field("value", value);
+        }
+        if let Some(hint) = &self.hint {
+            debug.field("hint", hint);
+        }
+        if let Some(backtrace) = &self.backtrace {
+            debug.field("backtrace", backtrace);
+        }
+        debug.finish()
     }
 }
 
 impl std::error::Error for ConstraintError {
     // This implements Rust's standard `Error` trait, allowing `ConstraintError`
     // to be used seamlessly with Rust's error handling mechanisms, similar to
-    // how custom exceptions are used in Kotlin.
+    // how custom exceptions are used in Kotlin. Providing the backtrace through
+    // `Error::provide` requires an unstable API, so it is exposed through
+    // `ConstraintError::backtrace` and the `Debug` output instead.
 }
 
 impl Clone for ConstraintError {
@@ -129,6 +365,7 @@ impl Clone for ConstraintError {
     ///
     /// # Example:
     /// ```rust
+    /// # use rustrict::prelude::*;
     /// let original = ConstraintError::new(|| "Original message".to_string());
     /// let clone = original.clone();
     /// assert_eq!(original.message(), clone.message());
@@ -136,6 +373,12 @@ impl Clone for ConstraintError {
     fn clone(&self) -> Self {
         ConstraintError {
             lazy_message: self.lazy_message.clone(),
+            backtrace: self.backtrace.clone(),
+            location: self.location,
+            rule: self.rule,
+            field: self.field.clone(),
+            value: self.value.clone(),
+            hint: self.hint.clone(),
         }
     }
 }
@@ -163,6 +406,7 @@ impl PartialEq for ConstraintError {
     ///
     /// # Example Usage:
     /// ```rust
+    /// # use rustrict::prelude::*;
     /// let error1 = ConstraintError::new(|| "Error message".to_string());
     /// let error2 = ConstraintError::new(|| "Error message".to_string());
     ///
@@ -193,4 +437,97 @@ mod tests {
             assert_eq!(error.message(), message);
         }
     }
+
+    #[test]
+    fn location_is_unknown_until_set() {
+        let error = ConstraintError::new(|| "message".to_string());
+        assert!(error.location().is_none());
+
+        let location = Location::caller();
+        let error = error.with_location(location);
+        assert_eq!(error.location(), Some(location));
+        assert_eq!(error.clone().location(), Some(location));
+    }
+
+    #[test]
+    fn details_are_recorded_and_cloned() {
+        let error = ConstraintError::new(|| "message".to_string())
+            .with_rule("HaveSize")
+            .with_field("/roles")
+            .with_value(&vec![1, 2]);
+
+        let record = error.clone().record();
+
+        assert_eq!(
+            record,
+            FailureRecord::new(
+                Some("HaveSize".to_string()),
+                "message".to_string(),
+                Some("/roles".to_string()),
+                Some("[1, 2]".to_string()),
+            )
+        );
+    }
+
+    #[test]
+    fn hints_are_displayed_after_the_message() {
+        let error = ConstraintError::new(|| "Name must be trimmed".to_string());
+        assert_eq!(error.to_string(), "Name must be trimmed");
+
+        let error = error.with_hint("try removing trailing whitespace");
+
+        assert_eq!(error.message(), "Name must be trimmed");
+        assert_eq!(
+            error.clone().to_string(),
+            "Name must be trimmed (hint: try removing trailing whitespace)"
+        );
+        assert_eq!(error.record().hint(), Some("try removing trailing whitespace"));
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn is_send_and_sync() {
+        assert_send_sync::<ConstraintError>();
+        assert_send_sync::<Vec<Result<(), ConstraintError>>>();
+    }
+
+    #[test]
+    fn can_be_moved_across_threads() {
+        let error = std::thread::spawn(|| ConstraintError::new(|| "worker failed".to_string()))
+            .join()
+            .unwrap();
+        let shared = Arc::new(error);
+
+        let message = {
+            let shared = shared.clone();
+            std::thread::spawn(move || shared.message()).join().unwrap()
+        };
+
+        assert_eq!(message, "worker failed");
+        assert_eq!(shared.message(), "worker failed");
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    fn backtrace_is_captured_on_creation() {
+        let error = ConstraintError::new(|| "message".to_string());
+        assert!(error.backtrace().is_some());
+        assert!(format!("{:?}", error).contains("backtrace"));
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    fn backtrace_is_shared_by_clones() {
+        let error = ConstraintError::new(|| "message".to_string());
+        let clone = error.clone();
+        assert!(std::ptr::eq(error.backtrace().unwrap(), clone.backtrace().unwrap()));
+    }
+
+    #[cfg(not(feature = "backtrace"))]
+    #[test]
+    fn backtrace_is_not_captured_by_default() {
+        let error = ConstraintError::new(|| "message".to_string());
+        assert!(error.backtrace().is_none());
+    }
 }
@@ -0,0 +1,190 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use std::fmt::Display;
+
+const RED: &str = "\x1b[31m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+/// The layout used to present a list of failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Every failure on a single line, separated by `; `, e.g. for log records.
+    Compact,
+    /// A header followed by one indented bullet per failure, e.g. for command line tools.
+    MultiLine,
+}
+
+/// Options to present validation failures.
+///
+/// `CompositeError` implements `Display` with a fixed format; `FailureFormat` lets developer
+/// tools choose between a compact single line, an indented multi-line list and ANSI colors. It is
+/// built like a Kotlin data class with `copy`: start from a preset and override what you need.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// let format = FailureFormat::multi_line().with_indent(4);
+///
+/// let text = format.render(["Roles must not be empty", "Tags must be unique"]);
+/// assert_eq!(
+///     text,
+///     "2 errors occurred:\n    - Roles must not be empty\n    - Tags must be unique"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailureFormat {
+    layout: Layout,
+    indent: usize,
+    color: bool,
+}
+
+impl FailureFormat {
+    /// Creates the options of a single line without colors, suitable for logs.
+    pub fn compact() -> Self {
+        FailureFormat {
+            layout: Layout::Compact,
+            indent: 0,
+            color: false,
+        }
+    }
+
+    /// Creates the options of an indented multi-line list without colors.
+    ///
+    /// Failures are indented by two spaces, which can be changed with `with_indent`.
+    pub fn multi_line() -> Self {
+        FailureFormat {
+            layout: Layout::MultiLine,
+            indent: 2,
+            color: false,
+        }
+    }
+
+    /// Returns a copy of these options with the given number of spaces before each bullet.
+    ///
+    /// The indentation only affects the `MultiLine` layout.
+    pub fn with_indent(self, indent: usize) -> Self {
+        FailureFormat { indent, ..self }
+    }
+
+    /// Returns a copy of these options that colors the output with ANSI escape codes.
+    ///
+    /// The header is printed in bold red and the bullets in red; the messages keep the default
+    /// color so they stay readable on any terminal theme.
+    pub fn with_color(self, color: bool) -> Self {
+        FailureFormat { color, ..self }
+    }
+
+    /// Returns the layout of these options.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Presents a list of failures with these options.
+    ///
+    /// # Parameters:
+    /// - `failures`: The failures to present, in order.
+    ///
+    /// # Returns:
+    /// The formatted failures, without a trailing newline.
+    pub fn render<I>(&self, failures: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        let failures: Vec<String> = failures.into_iter().map(|f| f.to_string()).collect();
+        let header = match failures.len() {
+            1 => "An error occurred:".to_string(),
+            count => format!("{} errors occurred:", count),
+        };
+        let header = self.paint(BOLD_RED, &header);
+        match self.layout {
+            Layout::Compact => format!("{} {}", header, failures.join("; ")),
+            Layout::MultiLine => {
+                let bullet = self.paint(RED, "-");
+                let indent = " ".repeat(self.indent);
+                failures.iter().fold(header, |text, failure| {
+                    // Continuation lines of a failure are aligned with the start of its message.
+                    let failure = failure.replace('\n', &format!("\n{}  ", indent));
+                    format!("{}\n{}{} {}", text, indent, bullet, failure)
+                })
+            }
+        }
+    }
+
+    /// Wraps the text in the given ANSI color if coloring is enabled.
+    fn paint(&self, color: &str, text: &str) -> String {
+        if self.color {
+            format!("{}{}{}", color, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+impl Default for FailureFormat {
+    /// Returns the `compact` options.
+    fn default() -> Self {
+        FailureFormat::compact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn compact_output_should_fit_in_a_single_line(
+            messages in vec("[a-zA-Z0-9 ]{1,20}", 1..10)
+        ) {
+            let text = FailureFormat::compact().render(&messages);
+
+            expect!(text.lines().count()).to(be_equal_to(1));
+        }
+
+        #[test]
+        fn multi_line_output_should_have_a_line_per_failure(
+            messages in vec("[a-zA-Z0-9 ]{1,20}", 1..10)
+        ) {
+            let text = FailureFormat::multi_line().render(&messages);
+
+            expect!(text.lines().count()).to(be_equal_to(messages.len() + 1));
+        }
+    }
+
+    #[test]
+    fn should_join_compact_failures() {
+        let text = FailureFormat::compact().render(["a", "b"]);
+
+        expect!(text).to(be_equal_to("2 errors occurred: a; b"));
+    }
+
+    #[test]
+    fn should_describe_a_single_failure() {
+        let text = FailureFormat::compact().render(["a"]);
+
+        expect!(text).to(be_equal_to("An error occurred: a"));
+    }
+
+    #[test]
+    fn should_align_continuation_lines_with_the_message() {
+        let text = FailureFormat::multi_line().render(["a\nb"]);
+
+        expect!(text).to(be_equal_to("An error occurred:\n  - a\n    b"));
+    }
+
+    #[test]
+    fn should_color_the_header_and_bullets() {
+        let text = FailureFormat::multi_line().with_color(true).render(["a"]);
+
+        expect!(text).to(be_equal_to(
+            "\x1b[1;31mAn error occurred:\x1b[0m\n  \x1b[31m-\x1b[0m a",
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+
+/// A plain snapshot of a failed rule.
+///
+/// `ConstraintError` evaluates its message lazily and may hold closures and backtraces, which is
+/// convenient while validating but awkward to store. A `FailureRecord` is the rendered, owned form
+/// of the error, comparable to a Kotlin data class, meant for audit trails and structured logs.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// let scope = RustrictScope::new();
+/// scope.validate_string("Role must be known", |s| s.must_be_one_of("guest", ["admin"]));
+///
+/// let record = &scope.failure_records()[0];
+/// assert_eq!(record.message(), r#"Role must be known: expected one of ["admin"], got "guest""#);
+/// assert_eq!(record.value(), Some(r#""guest""#));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FailureRecord {
+    rule: Option<String>,
+    message: String,
+    field: Option<String>,
+    value: Option<String>,
+    hint: Option<String>,
+}
+
+impl FailureRecord {
+    /// Creates a record from its parts.
+    ///
+    /// # Parameters:
+    /// - `rule`: The name of the rule that failed, if known.
+    /// - `message`: The rendered message of the failure.
+    /// - `field`: The path of the field that failed, if known.
+    /// - `value`: A `Debug` snapshot of the offending value, if known.
+    pub fn new(
+        rule: Option<String>,
+        message: String,
+        field: Option<String>,
+        value: Option<String>,
+    ) -> Self {
+        FailureRecord {
+            rule,
+            message,
+            field,
+            value,
+            hint: None,
+        }
+    }
+
+    /// Returns a copy of this record with a suggested fix for the offending value.
+    pub fn with_hint(mut self, hint: String) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    /// Returns the name of the rule that failed, like the type name of its constraint.
+    pub fn rule(&self) -> Option<&str> {
+        self.rule.as_deref()
+    }
+
+    /// Returns the rendered message of the failure.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the path of the field that failed, if known.
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// Returns a `Debug` snapshot of the offending value, if known.
+    ///
+    /// Only rules that can format their value record it, e.g. equality rules and the rules of a
+    /// `JsonScope`.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Returns the suggested fix for the offending value, if any.
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+}
@@ -4,5 +4,8 @@
  */
 
 pub mod collection_constraint_error;
-mod composite_error;
+pub mod composite_error;
 pub mod constraint_error;
+pub mod failure_format;
+pub mod failure_record;
+pub mod skipped_rule;
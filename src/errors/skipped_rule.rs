@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use std::fmt::{Display, Formatter};
+use std::panic::Location;
+
+/// A rule that was not evaluated because the deadline of its scope had passed.
+///
+/// Skipped rules are recorded as `RuleResult::Skipped`, next to passed and failed ones: they are
+/// not counted as failures, but a scope with skipped rules is never valid, since nothing is known
+/// about the values they would have checked. `RustrictScope::into_result` reports them next to the
+/// failures, each as a `SkippedRule` cause of the `CompositeError`.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # use std::time::Duration;
+/// let scope = RustrictScope::new().with_deadline(Duration::ZERO);
+/// scope.validate_string("Name must not be empty", |s| s.must("Ada", |name: &str| !name.is_empty()));
+///
+/// assert_eq!(scope.failure_count(), 0);
+/// assert_eq!(scope.skipped()[0].to_string(), "Name must not be empty: skipped after the deadline passed");
+/// assert!(!scope.is_valid());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedRule {
+    message: String,
+    location: &'static Location<'static>,
+}
+
+impl SkippedRule {
+    /// Creates a `SkippedRule` for a rule of the block with the given message.
+    pub(crate) fn new(message: String, location: &'static Location<'static>) -> Self {
+        SkippedRule { message, location }
+    }
+
+    /// Returns the message of the block the rule belongs to.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the source location of the rule.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl Display for SkippedRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: skipped after the deadline passed", self.message)
+    }
+}
+
+impl std::error::Error for SkippedRule {}
@@ -0,0 +1,419 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Validation of loosely-typed JSON documents.
+//!
+//! This module is available with the `json` feature. A `JsonScope` validates an arbitrary
+//! `serde_json::Value` tree before it is deserialized into a struct, addressing each value by its
+//! JSON pointer:
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! # use serde_json::Value;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let body = r#"{ "user": { "email": "a@b.cl", "roles": ["admin"] } }"#;
+//! let payload: Value = serde_json::from_str(body)?;
+//! let scope = JsonScope::new(&payload);
+//! scope.at("/user/roles").must::<[String], _>(NotBeEmpty);
+//! scope.at("/user/email").must(|email: &String| email.contains('@'));
+//! scope.into_result()?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::constraints::constraint::Constraint;
+use crate::constraints::not::Not;
+use crate::errors::composite_error::CompositeError;
+use crate::errors::constraint_error::ConstraintError;
+use crate::errors::failure_record::FailureRecord;
+use crate::rule_result::RuleResult;
+use crate::rustrict_scope::RustrictScope;
+use crate::string_scope::StringScope;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::panic::Location;
+
+/// A scope for validating the values of a JSON document by path.
+///
+/// The rules run on a `RustrictScope`, as one block per rule labelled with the path of its value,
+/// so they honour its deadline, observers and contexts, and soft rules record warnings in it.
+///
+/// # Fields:
+/// - `document`: The JSON document being validated.
+/// - `scope`: The scope that runs the rules and records their outcomes.
+pub struct JsonScope<'a> {
+    document: &'a Value,
+    scope: RustrictScope,
+}
+
+impl<'a> JsonScope<'a> {
+    /// Creates a new `JsonScope` over the given document, with no recorded results.
+    pub fn new(document: &'a Value) -> Self {
+        Self::with_scope(document, RustrictScope::new())
+    }
+
+    /// Creates a new `JsonScope` over the given document, running its rules on the given scope.
+    ///
+    /// # Parameters:
+    /// - `document`: The JSON document to validate.
+    /// - `scope`: The scope that runs the rules, with its deadline, observers and contexts.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # use serde_json::json;
+    /// # use std::time::Duration;
+    /// let payload = json!({ "roles": ["admin"] });
+    /// let scope = JsonScope::with_scope(
+    ///     &payload,
+    ///     RustrictScope::new().with_deadline(Duration::from_secs(1)),
+    /// );
+    /// scope.at("/roles").must::<[String], _>(NotBeEmpty);
+    /// assert!(scope.is_valid());
+    /// ```
+    pub fn with_scope(document: &'a Value, scope: RustrictScope) -> Self {
+        Self { document, scope }
+    }
+
+    /// Returns the scope that runs the rules, e.g. to read its warnings, skipped rules or summary.
+    pub fn scope(&self) -> &RustrictScope {
+        &self.scope
+    }
+
+    /// Selects the value at the given path, using the JSON pointer syntax (`/user/email`).
+    ///
+    /// # Parameters:
+    /// - `path`: The JSON pointer of the value to validate. The empty string selects the whole
+    ///   document.
+    ///
+    /// # Returns:
+    /// A `JsonPathScope` whose rules apply to the selected value.
+    pub fn at(&self, path: impl Into<String>) -> JsonPathScope<'_> {
+        JsonPathScope {
+            document: self.document,
+            path: path.into(),
+            scope: &self.scope,
+        }
+    }
+
+    /// Returns a copy of the results recorded so far, including the rules skipped after the
+    /// deadline.
+    pub fn results(&self) -> Vec<RuleResult> {
+        self.scope.results()
+    }
+
+    /// Returns a `FailureRecord` for each failed rule, in the order they were recorded.
+    ///
+    /// Every record holds the JSON pointer of its value as the field and, when the value exists, a
+    /// `Debug` snapshot of it.
+    pub fn failure_records(&self) -> Vec<FailureRecord> {
+        self.scope.failure_records()
+    }
+
+    /// Returns the failures recorded so far, grouped by the JSON pointer of their value.
+    ///
+    /// The failures of each path keep the order in which they were recorded.
+    pub fn failures_by_field(&self) -> HashMap<String, Vec<ConstraintError>> {
+        self.scope.failures_by_field()
+    }
+
+    /// Returns `true` if no rule has failed or been skipped so far.
+    pub fn is_valid(&self) -> bool {
+        self.scope.is_valid()
+    }
+
+    /// Consumes the scope, aggregating every failure into a `CompositeError`.
+    ///
+    /// # Returns:
+    /// - `Ok(())`: If no rule failed or was skipped.
+    /// - `Err(CompositeError)`: With the recorded errors, in the order they were recorded, followed
+    ///   by the skipped rules.
+    pub fn into_result(self) -> Result<(), CompositeError> {
+        self.scope.into_result()
+    }
+}
+
+/// The rules of a single path of a `JsonScope`.
+///
+/// Obtained through `JsonScope::at`. Each rule deserializes the selected value into the type
+/// checked by its constraint, or its owned form (like `Vec<T>` for `[T]`), and records a failure
+/// labelled with the path when the value is missing, has a different shape or does not satisfy the
+/// constraint.
+pub struct JsonPathScope<'a> {
+    document: &'a Value,
+    path: String,
+    scope: &'a RustrictScope,
+}
+
+impl JsonPathScope<'_> {
+    /// Validates that the selected value satisfies the specified constraint.
+    ///
+    /// # Parameters:
+    /// - `constraint`: The constraint that the value must satisfy.
+    #[track_caller]
+    pub fn must<T, C>(&self, constraint: C) -> &Self
+    where
+        T: ToOwned + ?Sized,
+        T::Owned: DeserializeOwned,
+        C: Constraint<T>,
+    {
+        let location = Location::caller();
+        self.run(|s| {
+            s.check(location, || self.evaluate(constraint, true, location));
+        })
+    }
+
+    /// Validates that the selected value does not satisfy the specified constraint.
+    ///
+    /// A missing or mistyped value is still recorded as a failure.
+    ///
+    /// # Parameters:
+    /// - `constraint`: The constraint that the value must not satisfy.
+    #[track_caller]
+    pub fn must_not<T, C>(&self, constraint: C) -> &Self
+    where
+        T: ToOwned + ?Sized,
+        T::Owned: DeserializeOwned,
+        C: Constraint<T>,
+    {
+        let location = Location::caller();
+        self.run(|s| {
+            s.check(location, || self.evaluate(constraint, false, location));
+        })
+    }
+
+    /// Checks that the selected value satisfies the specified constraint, as a soft rule.
+    ///
+    /// Like `StringScope::should`, a failure, including a missing or mistyped value, is recorded
+    /// as a warning of the underlying scope.
+    ///
+    /// # Parameters:
+    /// - `constraint`: The constraint that the value should satisfy.
+    #[track_caller]
+    pub fn should<T, C>(&self, constraint: C) -> &Self
+    where
+        T: ToOwned + ?Sized,
+        T::Owned: DeserializeOwned,
+        C: Constraint<T>,
+    {
+        let location = Location::caller();
+        self.run(|s| s.check_softly(location, || self.evaluate(constraint, true, location)))
+    }
+
+    /// Checks that the selected value does not satisfy the specified constraint, as a soft rule.
+    ///
+    /// # Parameters:
+    /// - `constraint`: The constraint that the value should not satisfy.
+    #[track_caller]
+    pub fn should_not<T, C>(&self, constraint: C) -> &Self
+    where
+        T: ToOwned + ?Sized,
+        T::Owned: DeserializeOwned,
+        C: Constraint<T>,
+    {
+        let location = Location::caller();
+        self.run(|s| s.check_softly(location, || self.evaluate(constraint, false, location)))
+    }
+
+    /// Runs a rule in a block of the underlying scope labelled with the path.
+    fn run(&self, rule: impl FnOnce(&StringScope)) -> &Self {
+        self.scope.validate_string(self.path.clone(), rule);
+        self
+    }
+
+    /// Checks the selected value against the constraint, creating the error of a failed rule.
+    fn evaluate<T, C>(
+        &self,
+        constraint: C,
+        condition: bool,
+        location: &'static Location<'static>,
+    ) -> Result<(), ConstraintError>
+    where
+        T: ToOwned + ?Sized,
+        T::Owned: DeserializeOwned,
+        C: Constraint<T>,
+    {
+        let path = self.path.clone();
+        let result = match self.document.pointer(&self.path) {
+            None => Err(ConstraintError::new(move || format!("{}: missing value", path))),
+            Some(json) => match <T::Owned as Deserialize>::deserialize(json) {
+                Err(error) => {
+                    let message = format!("{}: {}", path, error);
+                    Err(ConstraintError::new(move || message.clone()).with_value(json))
+                }
+                Ok(value) if constraint.validate(value.borrow()) == condition => Ok(()),
+                Ok(value) if condition => Err(constraint
+                    .generate_exception_with_value(value.borrow(), path)
+                    .with_value(json)),
+                Ok(value) => Err(Not(constraint)
+                    .generate_exception_with_value(value.borrow(), path)
+                    .with_value(json)),
+            },
+        };
+        result.map_err(|error| {
+            error
+                .with_location(location)
+                .with_rule(std::any::type_name::<C>())
+                .with_field(self.path.clone())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::be_empty::NotBeEmpty;
+    use crate::constraints::collections::have_no_duplicates::HaveNoDuplicates;
+    use crate::errors::skipped_rule::SkippedRule;
+    use crate::validation_observer::ValidationObserver;
+    use expectest::prelude::*;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn messages(scope: &JsonScope) -> Vec<String> {
+        scope
+            .results()
+            .iter()
+            .filter_map(RuleResult::failure)
+            .map(ConstraintError::message)
+            .collect()
+    }
+
+    #[test]
+    fn should_accept_a_valid_document() {
+        let payload = json!({ "user": { "email": "a@b.cl", "roles": ["admin"] } });
+        let scope = JsonScope::new(&payload);
+
+        scope.at("/user/email").must(|email: &String| email.contains('@'));
+        scope
+            .at("/user/roles")
+            .must::<[String], _>(NotBeEmpty)
+            .must::<[String], _>(HaveNoDuplicates);
+
+        expect!(scope.is_valid()).to(be_true());
+        expect!(scope.into_result()).to(be_ok());
+    }
+
+    #[test]
+    fn should_label_failures_with_their_path() {
+        let payload = json!({ "user": { "roles": ["admin", "admin"] } });
+        let scope = JsonScope::new(&payload);
+
+        scope.at("/user/roles").must::<[String], _>(HaveNoDuplicates);
+        scope.at("/user/roles").must_not::<[String], _>(NotBeEmpty);
+
+        expect!(messages(&scope)).to(be_equal_to(vec![
+            "/user/roles: element at index 1 is a duplicate".to_string(),
+            "/user/roles: expected NOT collection to not be empty".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn should_record_the_path_and_value_of_failures() {
+        let payload = json!({ "user": { "roles": [] } });
+        let scope = JsonScope::new(&payload);
+
+        scope.at("/user/roles").must::<[String], _>(NotBeEmpty);
+        scope.at("/user/email").must(|email: &String| email.contains('@'));
+
+        let records = scope.failure_records();
+        expect!(records[0].field()).to(be_equal_to(Some("/user/roles")));
+        expect!(records[0].value()).to(be_equal_to(Some("Array []")));
+        expect!(records[0].rule().unwrap().ends_with("NotBeEmpty")).to(be_true());
+        expect!(records[1].field()).to(be_equal_to(Some("/user/email")));
+        expect!(records[1].value()).to(be_none());
+        expect!(scope.failures_by_field()["/user/roles"].len()).to(be_equal_to(1));
+    }
+
+    #[test]
+    fn should_report_missing_values() {
+        let payload = json!({ "user": {} });
+        let scope = JsonScope::new(&payload);
+
+        scope.at("/user/email").must(|email: &String| email.contains('@'));
+
+        expect!(messages(&scope)).to(be_equal_to(vec!["/user/email: missing value".to_string()]));
+    }
+
+    #[test]
+    fn should_report_values_of_a_different_shape() {
+        let payload = json!({ "user": { "roles": "admin" } });
+        let scope = JsonScope::new(&payload);
+
+        scope.at("/user/roles").must::<[String], _>(NotBeEmpty);
+
+        let failures = messages(&scope);
+        expect!(failures.len()).to(be_equal_to(1));
+        expect!(failures[0].starts_with("/user/roles: invalid type")).to(be_true());
+        expect!(scope.into_result()).to(be_err());
+    }
+
+    #[derive(Default)]
+    struct PathRecorder {
+        failed: Mutex<Vec<String>>,
+    }
+
+    impl ValidationObserver for PathRecorder {
+        fn on_failure(&self, message: &str, _error: &ConstraintError) {
+            self.failed.lock().unwrap().push(message.to_string());
+        }
+    }
+
+    #[test]
+    fn should_notify_the_observers_of_the_scope() {
+        let payload = json!({ "user": { "roles": [] } });
+        let recorder = Arc::new(PathRecorder::default());
+        let mut scope = RustrictScope::new();
+        scope.register_observer(recorder.clone());
+        let scope = JsonScope::with_scope(&payload, scope);
+
+        scope.at("/user/roles").must::<[String], _>(NotBeEmpty);
+
+        expect!(recorder.failed.lock().unwrap().clone())
+            .to(be_equal_to(vec!["/user/roles".to_string()]));
+    }
+
+    #[test]
+    fn should_skip_the_rules_after_the_deadline() {
+        let payload = json!({ "user": { "roles": [] } });
+        let scope =
+            JsonScope::with_scope(&payload, RustrictScope::new().with_deadline(Duration::ZERO));
+
+        scope.at("/user/roles").must::<[String], _>(NotBeEmpty);
+
+        expect!(scope.results()[0].skipped().map(SkippedRule::message))
+            .to(be_some().value("/user/roles"));
+        expect!(scope.is_valid()).to(be_false());
+    }
+
+    #[test]
+    fn should_record_soft_rules_as_warnings() {
+        let payload = json!({ "user": { "tags": [] } });
+        let scope = JsonScope::new(&payload);
+
+        scope.at("/user/tags").should::<[String], _>(NotBeEmpty);
+
+        expect!(scope.is_valid()).to(be_true());
+        let warnings = scope.scope().warnings();
+        expect!(warnings.len()).to(be_equal_to(1));
+        expect!(warnings[0].field()).to(be_equal_to(Some("/user/tags")));
+    }
+
+    #[test]
+    fn should_summarize_the_rules_by_path() {
+        let payload = json!({ "user": { "roles": ["admin"], "email": "ada" } });
+        let scope = JsonScope::new(&payload);
+
+        scope.at("/user/roles").must::<[String], _>(NotBeEmpty);
+        scope.at("/user/email").must(|email: &String| email.contains('@'));
+
+        let summary = scope.scope().summary();
+        expect!(summary.evaluated()).to(be_equal_to(2));
+        expect!(summary.failed()).to(be_equal_to(1));
+        expect!(summary.group("/user/email").unwrap().failed()).to(be_equal_to(1));
+    }
+}
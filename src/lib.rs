@@ -2,12 +2,55 @@
  * Copyright (c) 2024, Ignacio Slater M.
  * 2-Clause BSD License.
  */
+//! Declarative validation rules for Rust values, ported from the Strait Kotlin DSL.
+//!
+//! Most applications only need the `prelude`:
+//!
+//! ```rust
+//! use rustrict::prelude::*;
+//!
+//! let scope = RustrictScope::new();
+//! scope.validate_string("Roles must not repeat", |s| s.must(vec!["admin", "admin"], HaveNoDuplicates));
+//! assert!(scope.into_result().is_err());
+//! ```
 use std::borrow::Cow;
-use crate::constraints::constraint::Constraint;
+use crate::constraints::constraint::{AsSubject, Constraint};
+use crate::constraints::not::negate;
 
-mod constraints;
-mod errors;
-mod string_scope;
+#[cfg(feature = "anyhow")]
+pub mod anyhow_support;
+pub mod batch;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod constraints;
+pub mod dsl;
+pub mod errors;
+#[cfg(feature = "json")]
+pub mod json_scope;
+pub mod prelude;
+#[cfg(any(test, feature = "proptest-support"))]
+pub mod proptest_support;
+#[cfg(any(feature = "config", feature = "wasm"))]
+mod rules;
+pub mod rule_result;
+pub mod rustrict_scope;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod string_scope;
+pub mod summary;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod validate;
+pub mod validated;
+pub mod validated_builder;
+pub mod validation_observer;
+#[cfg(feature = "validator-compat")]
+pub mod validator_compat;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use crate::batch::{validate_batch, validate_batch_with_limit};
+pub use crate::validated::validated;
 
 /// A struct representing a clause in a validation process, holding a value and an associated
 /// message.
@@ -45,6 +88,7 @@ mod string_scope;
 /// # Example
 ///
 /// ```rust
+/// # use rustrict::prelude::*;
 /// use std::borrow::Cow;
 ///
 /// fn main() {
@@ -60,9 +104,9 @@ mod string_scope;
 /// }
 /// ```
 #[derive(Debug)]
-struct ConstraintClause<'a, T> {
-    message: Cow<'a, str>,
-    value: T,
+pub struct ConstraintClause<'a, T> {
+    pub message: Cow<'a, str>,
+    pub value: T,
 }
 
 impl<'a, T> ConstraintClause<'a, T> {
@@ -75,8 +119,9 @@ impl<'a, T> ConstraintClause<'a, T> {
     ///
     /// # Parameters
     ///
-    /// - `constraint`: A reference to a type that implements the `Constraint<T>` trait. The
-    ///     constraint is applied to the value to determine if it meets the required condition.
+    /// - `constraint`: A reference to a type that implements the `Constraint<U>` trait, for any
+    ///   `U` the value can be viewed as (see `AsSubject`). The constraint is applied to the value
+    ///   to determine if it meets the required condition.
     ///
     /// # Returns
     ///
@@ -87,6 +132,8 @@ impl<'a, T> ConstraintClause<'a, T> {
     /// # Example
     ///
     /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # use std::borrow::Cow;
     /// let clause = ConstraintClause {
     ///     message: Cow::Borrowed("Collection size must be 3"),
     ///     value: vec![1, 2, 3],
@@ -95,11 +142,16 @@ impl<'a, T> ConstraintClause<'a, T> {
     /// let result = clause.must(&HaveSize::with_exact_size(3));
     /// assert!(result.is_ok());
     /// ```
-    fn must(&self, constraint: &impl Constraint<T>) -> Result<(), String> {
-        if constraint.validate(&self.value) {
+    pub fn must<U>(&self, constraint: &impl Constraint<U>) -> Result<(), String>
+    where
+        U: ?Sized,
+        T: AsSubject<U>,
+    {
+        let value = self.value.as_subject();
+        if constraint.validate(value) {
             Ok(())
         } else {
-            Err(constraint.generate_error_message(&self.message))
+            Err(constraint.generate_error_message(value, &self.message))
         }
     }
 
@@ -112,18 +164,21 @@ impl<'a, T> ConstraintClause<'a, T> {
     ///
     /// # Parameters
     ///
-    /// - `constraint`: A reference to a type that implements the `Constraint<T>` trait. The
-    ///     constraint is applied to the value to determine if it should fail the condition.
+    /// - `constraint`: A reference to a type that implements the `Constraint<U>` trait, for any
+    ///   `U` the value can be viewed as (see `AsSubject`). The constraint is applied to the value
+    ///   to determine if it should fail the condition.
     ///
     /// # Returns
     ///
     /// - `Ok(())`: If the value does not satisfy the constraint.
-    /// - `Err(String)`: If the value satisfies the constraint, with an error message generated by
-    ///     the constraint.
+    /// - `Err(String)`: If the value satisfies the constraint, with the error message of the
+    ///   constraint negated, e.g. `expected NOT size 3, got 3`.
     ///
     /// # Example
     ///
     /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # use std::borrow::Cow;
     /// let clause = ConstraintClause {
     ///     message: Cow::Borrowed("Collection size must not be 2"),
     ///     value: vec![1, 2, 3],
@@ -132,11 +187,17 @@ impl<'a, T> ConstraintClause<'a, T> {
     /// let result = clause.must_not(&HaveSize::with_exact_size(2));
     /// assert!(result.is_ok());
     /// ```
-    fn must_not(&self, constraint: &impl Constraint<T>) -> Result<(), String> {
-        if !constraint.validate(&self.value) {
+    pub fn must_not<U>(&self, constraint: &impl Constraint<U>) -> Result<(), String>
+    where
+        U: ?Sized,
+        T: AsSubject<U>,
+    {
+        let value = self.value.as_subject();
+        if !constraint.validate(value) {
             Ok(())
         } else {
-            Err(constraint.generate_error_message(&self.message))
+            let error = constraint.generate_exception_with_value(value, self.message.to_string());
+            Err(format!("{}: {}", self.message, negate(error, self.message.to_string())))
         }
     }
 }
@@ -150,7 +211,7 @@ impl<'a, T> ConstraintClause<'a, T> {
 ///
 /// # Syntax
 ///
-/// ```rust
+/// ```text
 /// constrained!(message, value)
 /// ```
 ///
@@ -162,6 +223,7 @@ impl<'a, T> ConstraintClause<'a, T> {
 /// # Example
 ///
 /// ```rust
+/// # use rustrict::prelude::*;
 /// let clause = constrained!("Collection size must be 3", vec![1, 2, 3]);
 ///
 /// match clause.must(&HaveSize::with_exact_size(3)) {
@@ -173,15 +235,110 @@ impl<'a, T> ConstraintClause<'a, T> {
 /// In this example, the `constrained!` macro creates a `ConstraintClause` where the `message` 
 /// is `"Collection size must be 3"` and the `value` is a vector containing `[1, 2, 3]`. This clause 
 /// can then be validated against a `HaveSize` constraint.
+#[macro_export]
 macro_rules! constrained {
     ($message:expr, $value:expr) => {
-        ConstraintClause {
+        $crate::ConstraintClause {
             message: ::std::borrow::Cow::from($message),
             value: $value,
         }
     };
 }
 
+/// A macro that returns early from the enclosing function if a scope has recorded any failure.
+///
+/// `check!(scope)` is a shorthand for `scope.checked()?`: the failures recorded so far are
+/// aggregated into a `CompositeError` and propagated with `?`, so they are converted into the
+/// error type of the enclosing function. The scope is left untouched, so validation can go on
+/// after a successful check.
+///
+/// # Syntax
+///
+/// ```text
+/// check!(scope)
+/// ```
+///
+/// - `scope`: An expression evaluating to a `RustrictScope` or a reference to one.
+///
+/// # Example
+///
+/// ```rust
+/// # use rustrict::prelude::*;
+/// fn create_team(members: Vec<&str>) -> Result<String, Box<dyn std::error::Error>> {
+///     let scope = RustrictScope::new();
+///     scope.validate_string("Members must not be empty", |s| s.must(members.clone(), NotBeEmpty));
+///     check!(scope);
+///     Ok(members.join(", "))
+/// }
+///
+/// assert!(create_team(vec![]).is_err());
+/// ```
+#[macro_export]
+macro_rules! check {
+    ($scope:expr) => {
+        $scope.checked()?
+    };
+}
+
+/// A macro that declares validation rules with the syntax of the Kotlin DSL.
+///
+/// Each block runs its rules in a `validate_string` block with the given message, and the macro
+/// evaluates to the `into_result()` of the scope, a `Result<(), CompositeError>`. A rule names a
+/// value, `must` or `must not`, and one of the functions of the `dsl` module, whose parentheses
+/// can be left out when it takes no arguments. Values are borrowed, so they can appear in several
+/// rules.
+///
+/// # Syntax
+///
+/// ```text
+/// constraints! {
+///     message {
+///         value must rule(args);
+///         value must not rule(args);
+///     }
+/// }
+/// ```
+///
+/// - `message`: A string literal with the message of the block.
+/// - `value`: A variable, or any expression wrapped in parentheses, like `(user.name)`.
+/// - `rule`: The name of a function of the `dsl` module, like `have_length` or `satisfy`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rustrict::prelude::*;
+/// let username = String::from("  ");
+///
+/// let result = constraints! {
+///     "Username must be valid" {
+///         username must not_be_blank;
+///         username must have_length(3..=20);
+///         (username.trim()) must not match_glob("admin*");
+///     }
+/// };
+/// assert_eq!(result.unwrap_err().errors().len(), 2);
+/// ```
+#[macro_export]
+macro_rules! constraints {
+    (@rules $s:ident;) => {};
+    (@rules $s:ident; $value:tt must not $rule:ident $(($($args:tt)*))?; $($rest:tt)*) => {
+        $crate::dsl::rule($s, &$value, $crate::dsl::$rule($($($args)*)?), false);
+        $crate::constraints!(@rules $s; $($rest)*);
+    };
+    (@rules $s:ident; $value:tt must $rule:ident $(($($args:tt)*))?; $($rest:tt)*) => {
+        $crate::dsl::rule($s, &$value, $crate::dsl::$rule($($($args)*)?), true);
+        $crate::constraints!(@rules $s; $($rest)*);
+    };
+    ($($message:literal { $($rules:tt)* })*) => {{
+        let scope = $crate::rustrict_scope::RustrictScope::new();
+        $(
+            scope.validate_string($message, |s| {
+                $crate::constraints!(@rules s; $($rules)*);
+            });
+        )*
+        scope.into_result()
+    }};
+}
 
 #[cfg(test)]
 mod tests {
@@ -221,6 +378,57 @@ mod tests {
     fn test_can_validate_a_must_not_clause_with_error() {
         let clause = constrained!("Test message", vec![1, 2, 3]);
         let result = clause.must_not(&HaveSize::with_exact_size(3));
-        expect!(result).to(be_err());
+        expect!(result.clone()).to(be_err());
+        expect!(result.unwrap_err().ends_with("expected NOT size 3, got 3")).to(be_true());
+    }
+
+    #[test]
+    fn test_can_declare_rules_with_the_constraints_macro() {
+        let username = "ada".to_string();
+        let roles = vec!["admin", "admin"];
+
+        let result = constraints! {
+            "Username must be valid" {
+                username must not_be_blank;
+                username must have_length(3..=20);
+                (username.to_uppercase()) must not be_equal_to("ADA".to_string());
+            }
+            "Roles must be unique" {
+                roles must have_no_duplicates;
+                roles must satisfy(HaveSize::with_exact_size(2));
+            }
+        };
+
+        let messages: Vec<String> = result
+            .unwrap_err()
+            .errors()
+            .iter()
+            .map(|error| error.to_string())
+            .collect();
+        expect!(messages).to(be_equal_to(vec![
+            r#"Username must be valid: expected NOT "ADA", got "ADA""#.to_string(),
+            "Roles must be unique: element at index 1 is a duplicate \
+             (hint: remove the element at index 1)"
+                .to_string(),
+        ]));
+    }
+
+    #[test]
+    fn test_describes_the_negated_expectation_of_a_must_not_rule() {
+        let name = "ab";
+
+        let result = constraints! {
+            "Name" {
+                name must not have_length(2);
+            }
+        };
+
+        expect!(result.unwrap_err().errors()[0].to_string())
+            .to(be_equal_to("Name: expected NOT 2 characters, got 2"));
+    }
+
+    #[test]
+    fn test_can_declare_no_rules_with_the_constraints_macro() {
+        expect!(constraints! {}).to(be_ok());
     }
 }
@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! The types needed to declare and run validations, in a single import.
+//!
+//! ```rust
+//! use rustrict::prelude::*;
+//!
+//! let scope = RustrictScope::new();
+//! scope.validate_string("Roles must not be empty", |s| s.must(vec!["admin"], NotBeEmpty));
+//! assert!(scope.into_result().is_ok());
+//! ```
+pub use crate::constraints::be_equal_to::{BeEqualTo, BeOneOf};
+pub use crate::constraints::cached::Cached;
+pub use crate::constraints::collections::be_empty::{BeEmpty, NotBeEmpty};
+pub use crate::constraints::collections::be_sorted::{BeSorted, BeSortedBy};
+pub use crate::constraints::collections::be_subset_of::{BeSubsetOf, BeSupersetOf};
+pub use crate::constraints::collections::contain_element::{ContainAll, ContainAny, ContainElement};
+pub use crate::constraints::collections::have_no_duplicates::HaveNoDuplicates;
+pub use crate::constraints::collections::have_size::HaveSize;
+pub use crate::constraints::constraint::{AsSubject, Constraint};
+pub use crate::constraints::formats::be_hostname::BeHostname;
+pub use crate::constraints::formats::be_ip_address::{BeInCidr, BeIpAddress, BeIpv4, BeIpv6};
+pub use crate::constraints::formats::pass_luhn_check::PassLuhnCheck;
+pub use crate::constraints::not::{not, Not};
+pub use crate::constraints::numeric::be_close_to::{BeCloseTo, Tolerance};
+pub use crate::constraints::numeric::be_in_range::BeInRange;
+pub use crate::constraints::strings::be_blank::BeBlank;
+pub use crate::constraints::strings::have_length::{HaveLength, LengthUnit};
+pub use crate::constraints::strings::match_glob::MatchGlob;
+pub use crate::constraints::with_exception::{with_exception, WithException};
+pub use crate::errors::collection_constraint_error::CollectionConstraintError;
+pub use crate::errors::composite_error::CompositeError;
+pub use crate::errors::constraint_error::ConstraintError;
+pub use crate::errors::failure_format::{FailureFormat, Layout};
+pub use crate::errors::failure_record::FailureRecord;
+pub use crate::errors::skipped_rule::SkippedRule;
+#[cfg(feature = "json")]
+pub use crate::json_scope::{JsonPathScope, JsonScope};
+pub use crate::rule_result::RuleResult;
+pub use crate::rustrict_scope::{Checkpoint, Failures, RustrictScope, ValidateIter};
+pub use crate::string_scope::{BlockResult, RuleOutcome, StringScope};
+pub use crate::summary::{GroupSummary, Summary};
+pub use crate::validate::Validate;
+pub use crate::validated::Validated;
+pub use crate::validated_builder::ValidatedBuilder;
+pub use crate::validation_observer::ValidationObserver;
+pub use crate::{check, constrained, constraints, ConstraintClause};
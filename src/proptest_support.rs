@@ -0,0 +1,427 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Proptest strategies that generate values satisfying or violating the built-in constraints.
+//!
+//! This module is available with the `proptest-support` feature. It lets downstream crates
+//! property-test their validation layers without hand-writing generators for every rule, much like
+//! combining Kotest's `Arb` generators with a filter in Kotlin.
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! # use rustrict::proptest_support::*;
+//! # use proptest::prelude::*;
+//! # use proptest::test_runner::TestRunner;
+//! # fn register(ids: Vec<u32>) -> Result<(), CompositeError> {
+//! #     let scope = RustrictScope::new();
+//! #     scope.validate_string("Ids must be unique", |s| s.must(ids, HaveNoDuplicates));
+//! #     scope.into_result()
+//! # }
+//! let mut runner = TestRunner::default();
+//! runner
+//!     .run(&arb_matching::<Vec<u32>, _>(&HaveNoDuplicates), |ids| {
+//!         prop_assert!(register(ids).is_ok());
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+use crate::constraints::collections::be_empty::{BeEmpty, NotBeEmpty};
+use crate::constraints::collections::be_sorted::BeSorted;
+use crate::constraints::collections::be_subset_of::{BeSubsetOf, BeSupersetOf};
+use crate::constraints::collections::contain_element::{ContainAll, ContainAny, ContainElement};
+use crate::constraints::collections::have_no_duplicates::HaveNoDuplicates;
+use crate::constraints::collections::have_size::HaveSize;
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+use proptest::sample::{select, Index};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// The maximum number of elements in the collections generated by the strategies of this module,
+/// unless a constraint requires more.
+const MAX_GENERATED_SIZE: usize = 32;
+
+/// A constraint that can generate values on both sides of its condition.
+///
+/// # Type Parameters:
+/// - `T`: The type of the generated values.
+pub trait ConstraintStrategy<T: Debug> {
+    /// Returns a strategy producing values that satisfy the constraint.
+    fn matching(&self) -> BoxedStrategy<T>;
+
+    /// Returns a strategy producing values that do not satisfy the constraint.
+    fn violating(&self) -> BoxedStrategy<T>;
+}
+
+/// Returns a strategy producing values that satisfy the given constraint.
+///
+/// # Parameters:
+/// - `constraint`: The constraint the generated values must satisfy.
+pub fn arb_matching<T, C>(constraint: &C) -> BoxedStrategy<T>
+where
+    T: Debug,
+    C: ConstraintStrategy<T> + ?Sized,
+{
+    constraint.matching()
+}
+
+/// Returns a strategy producing values that violate the given constraint.
+///
+/// # Parameters:
+/// - `constraint`: The constraint the generated values must not satisfy.
+pub fn arb_violating<T, C>(constraint: &C) -> BoxedStrategy<T>
+where
+    T: Debug,
+    C: ConstraintStrategy<T> + ?Sized,
+{
+    constraint.violating()
+}
+
+/// Returns a strategy producing collections of arbitrary elements with the given sizes.
+fn arb_vec<T>(size: impl Into<SizeRange>) -> BoxedStrategy<Vec<T>>
+where
+    T: Arbitrary + 'static,
+{
+    vec(any::<T>(), size).boxed()
+}
+
+/// Returns a strategy producing collections whose size is one of `sizes`.
+///
+/// # Panics
+/// If `sizes` is empty, since no size up to `MAX_GENERATED_SIZE` fits.
+fn arb_vec_with_size_in<T>(sizes: Vec<usize>) -> BoxedStrategy<Vec<T>>
+where
+    T: Arbitrary + 'static,
+{
+    assert!(
+        !sizes.is_empty(),
+        "no collection of up to {} elements has a size accepted by the constraint",
+        MAX_GENERATED_SIZE
+    );
+    select(sizes).prop_flat_map(arb_vec).boxed()
+}
+
+/// Returns the sizes up to `MAX_GENERATED_SIZE` for which `predicate` returns `expected`.
+fn sizes_where(predicate: &(dyn Fn(usize) -> bool + Send + Sync), expected: bool) -> Vec<usize> {
+    (0..=MAX_GENERATED_SIZE).filter(|size| predicate(*size) == expected).collect()
+}
+
+impl<T: Arbitrary + 'static> ConstraintStrategy<Vec<T>> for HaveSize {
+    /// Produces collections with a size that the constraint accepts.
+    ///
+    /// # Panics
+    /// If the predicate rejects every size up to `MAX_GENERATED_SIZE`.
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        match self.expected_size {
+            Some(size) => arb_vec(size),
+            None => arb_vec_with_size_in(sizes_where(self.predicate.as_ref(), true)),
+        }
+    }
+
+    /// Produces collections with a size, up to `MAX_GENERATED_SIZE`, that the predicate rejects.
+    ///
+    /// # Panics
+    /// If the predicate accepts every size up to `MAX_GENERATED_SIZE`.
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        arb_vec_with_size_in(sizes_where(self.predicate.as_ref(), false))
+    }
+}
+
+impl<T: Arbitrary + 'static> ConstraintStrategy<Vec<T>> for BeEmpty {
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        arb_vec(0)
+    }
+
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        arb_vec(1..=MAX_GENERATED_SIZE)
+    }
+}
+
+impl<T: Arbitrary + 'static> ConstraintStrategy<Vec<T>> for NotBeEmpty {
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        arb_vec(1..=MAX_GENERATED_SIZE)
+    }
+
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        arb_vec(0)
+    }
+}
+
+impl<T: Arbitrary + Ord + 'static> ConstraintStrategy<Vec<T>> for BeSorted {
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        arb_vec(0..=MAX_GENERATED_SIZE)
+            .prop_map(|mut v| {
+                v.sort();
+                v
+            })
+            .boxed()
+    }
+
+    /// Produces collections where a greater element appears before a smaller one.
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        let pair = (any::<T>(), any::<T>())
+            .prop_filter("elements must be different", |(a, b)| a != b)
+            .prop_map(|(a, b)| if a < b { (b, a) } else { (a, b) });
+        (arb_vec(0..=MAX_GENERATED_SIZE), pair, any::<Index>(), any::<Index>())
+            .prop_map(|(mut v, (greater, smaller), first, second)| {
+                let first = first.index(v.len() + 1);
+                v.insert(first, greater);
+                v.insert(first + 1 + second.index(v.len() - first), smaller);
+                v
+            })
+            .boxed()
+    }
+}
+
+impl<T> ConstraintStrategy<Vec<T>> for HaveNoDuplicates
+where
+    T: Arbitrary + Eq + Hash + Clone + 'static,
+{
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        arb_vec(0..=MAX_GENERATED_SIZE)
+            .prop_map(|v| {
+                let mut unique: Vec<T> = Vec::with_capacity(v.len());
+                for element in v {
+                    if !unique.contains(&element) {
+                        unique.push(element);
+                    }
+                }
+                unique
+            })
+            .boxed()
+    }
+
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        (arb_vec::<T>(1..=MAX_GENERATED_SIZE), any::<Index>())
+            .prop_map(|(mut v, index)| {
+                v.push(v[index.index(v.len())].clone());
+                v
+            })
+            .prop_shuffle()
+            .boxed()
+    }
+}
+
+impl<T> ConstraintStrategy<Vec<T>> for ContainElement<T>
+where
+    T: Arbitrary + PartialEq + Clone + 'static,
+{
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        let element = self.0.clone();
+        (arb_vec(0..=MAX_GENERATED_SIZE), any::<Index>())
+            .prop_map(move |(mut v, index)| {
+                v.insert(index.index(v.len() + 1), element.clone());
+                v
+            })
+            .boxed()
+    }
+
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        let element = self.0.clone();
+        arb_vec(0..=MAX_GENERATED_SIZE)
+            .prop_map(move |mut v| {
+                v.retain(|e| *e != element);
+                v
+            })
+            .boxed()
+    }
+}
+
+impl<T> ConstraintStrategy<Vec<T>> for ContainAll<T>
+where
+    T: Arbitrary + PartialEq + Clone + 'static,
+{
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        let expected = self.0.clone();
+        arb_vec(0..=MAX_GENERATED_SIZE)
+            .prop_map(move |mut v| {
+                v.extend(expected.iter().cloned());
+                v
+            })
+            .prop_shuffle()
+            .boxed()
+    }
+
+    /// Produces collections missing at least one of the expected elements.
+    ///
+    /// # Panics
+    /// If there are no expected elements, since every collection contains all of them.
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        assert!(
+            !self.0.is_empty(),
+            "an empty ContainAll can not be violated: every collection contains all of no elements"
+        );
+        (arb_vec(0..=MAX_GENERATED_SIZE), select(self.0.clone()))
+            .prop_map(|(mut v, missing)| {
+                v.retain(|e| *e != missing);
+                v
+            })
+            .boxed()
+    }
+}
+
+impl<T> ConstraintStrategy<Vec<T>> for ContainAny<T>
+where
+    T: Arbitrary + PartialEq + Clone + 'static,
+{
+    /// Produces collections containing one of the expected elements.
+    ///
+    /// # Panics
+    /// If there are no expected elements, since no collection contains one of them.
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        assert!(
+            !self.0.is_empty(),
+            "an empty ContainAny can not be satisfied: no collection contains one of no elements"
+        );
+        (arb_vec(0..=MAX_GENERATED_SIZE), select(self.0.clone()), any::<Index>())
+            .prop_map(|(mut v, element, index)| {
+                v.insert(index.index(v.len() + 1), element);
+                v
+            })
+            .boxed()
+    }
+
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        let expected = self.0.clone();
+        arb_vec(0..=MAX_GENERATED_SIZE)
+            .prop_map(move |mut v| {
+                v.retain(|e| !expected.contains(e));
+                v
+            })
+            .boxed()
+    }
+}
+
+impl<T> ConstraintStrategy<Vec<T>> for BeSubsetOf<T>
+where
+    T: Arbitrary + Eq + Hash + Clone + 'static,
+{
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        if self.reference.is_empty() {
+            return arb_vec(0);
+        }
+        vec(select(self.reference.clone()), 0..=MAX_GENERATED_SIZE).boxed()
+    }
+
+    /// Produces subsets of the reference set with an extraneous element inserted.
+    ///
+    /// The extraneous element is the first of a batch of candidates that is outside the reference
+    /// set, so a value is only discarded if every candidate is allowed, which is unlikely unless
+    /// the reference set covers most values of `T`.
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        let reference = self.reference.clone();
+        let extraneous = vec(any::<T>(), MAX_GENERATED_SIZE).prop_filter_map(
+            "every candidate must not be in the reference set",
+            move |candidates| candidates.into_iter().find(|e| !reference.contains(e)),
+        );
+        (self.matching(), extraneous, any::<Index>())
+            .prop_map(|(mut v, element, index)| {
+                v.insert(index.index(v.len() + 1), element);
+                v
+            })
+            .boxed()
+    }
+}
+
+impl<T> ConstraintStrategy<Vec<T>> for BeSupersetOf<T>
+where
+    T: Arbitrary + Eq + Hash + Clone + 'static,
+{
+    fn matching(&self) -> BoxedStrategy<Vec<T>> {
+        let reference = self.reference.clone();
+        arb_vec(0..=MAX_GENERATED_SIZE)
+            .prop_map(move |mut v| {
+                v.extend(reference.iter().cloned());
+                v
+            })
+            .prop_shuffle()
+            .boxed()
+    }
+
+    /// Produces collections missing at least one element of the reference set.
+    ///
+    /// # Panics
+    /// If the reference set is empty, since every collection is a superset of it.
+    fn violating(&self) -> BoxedStrategy<Vec<T>> {
+        assert!(
+            !self.reference.is_empty(),
+            "an empty BeSupersetOf can not be violated: every collection is a superset of it"
+        );
+        let reference = self.reference.clone();
+        (arb_vec(0..=MAX_GENERATED_SIZE), select(self.reference.clone()))
+            .prop_map(move |(mut v, missing)| {
+                v.extend(reference.iter().cloned());
+                v.retain(|e| *e != missing);
+                v
+            })
+            .prop_shuffle()
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::constraint::Constraint;
+    use expectest::prelude::*;
+
+    /// Checks that the strategies of a constraint agree with its `validate` method.
+    macro_rules! strategies_agree_with_validate {
+        ($name:ident, $element:ty, $constraint:expr) => {
+            mod $name {
+                use super::*;
+
+                proptest! {
+                    #[test]
+                    fn should_generate_matching_values(
+                        value in arb_matching::<Vec<$element>, _>(&$constraint)
+                    ) {
+                        expect!($constraint.validate(&value)).to(be_true());
+                    }
+
+                    #[test]
+                    fn should_generate_violating_values(
+                        value in arb_violating::<Vec<$element>, _>(&$constraint)
+                    ) {
+                        expect!($constraint.validate(&value)).to(be_false());
+                    }
+                }
+            }
+        };
+    }
+
+    strategies_agree_with_validate!(have_exact_size, u8, HaveSize::with_exact_size(4));
+    strategies_agree_with_validate!(have_size, u8, HaveSize::new(|size| size % 2 == 0));
+    strategies_agree_with_validate!(have_rare_size, u8, HaveSize::new(|size| size == 7));
+    strategies_agree_with_validate!(be_sorted_booleans, bool, BeSorted);
+    strategies_agree_with_validate!(be_empty, u8, BeEmpty);
+    strategies_agree_with_validate!(not_be_empty, u8, NotBeEmpty);
+    strategies_agree_with_validate!(be_sorted, u8, BeSorted);
+    strategies_agree_with_validate!(have_no_duplicates, bool, HaveNoDuplicates);
+    strategies_agree_with_validate!(contain_element, bool, ContainElement(true));
+    strategies_agree_with_validate!(contain_all, u8, ContainAll(vec![1, 2, 3]));
+    strategies_agree_with_validate!(contain_any, u8, ContainAny(vec![1, 2, 3]));
+    strategies_agree_with_validate!(be_subset_of, u8, BeSubsetOf::new([1, 2, 3]));
+    strategies_agree_with_validate!(be_subset_of_most, u8, BeSubsetOf::new(0..=200));
+    strategies_agree_with_validate!(contain_all_booleans, bool, ContainAll(vec![true, false]));
+    strategies_agree_with_validate!(be_superset_of, u8, BeSupersetOf::new([1, 2, 3]));
+
+    #[test]
+    #[should_panic(expected = "has a size accepted by the constraint")]
+    fn should_refuse_to_match_a_size_that_can_not_be_generated() {
+        let _ = arb_matching::<Vec<u8>, _>(&HaveSize::new(|size| size > MAX_GENERATED_SIZE));
+    }
+
+    #[test]
+    #[should_panic(expected = "an empty ContainAll can not be violated")]
+    fn should_refuse_to_violate_an_empty_contain_all() {
+        let _ = arb_violating::<Vec<u8>, _>(&ContainAll(vec![]));
+    }
+
+    #[test]
+    #[should_panic(expected = "an empty ContainAny can not be satisfied")]
+    fn should_refuse_to_match_an_empty_contain_any() {
+        let _ = arb_matching::<Vec<u8>, _>(&ContainAny(vec![]));
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::errors::constraint_error::ConstraintError;
+use crate::errors::skipped_rule::SkippedRule;
+
+/// The outcome of a single rule, as recorded by a scope.
+///
+/// Besides passing and failing, a rule can be skipped when the deadline of its scope had passed
+/// (see `RustrictScope::with_deadline`). This is the Rust counterpart of a Kotlin sealed class
+/// with three subclasses: matching on it forces every outcome to be handled.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # use std::time::Duration;
+/// let scope = RustrictScope::new().with_deadline(Duration::ZERO);
+/// scope.validate_string("Name must not be empty", |s| s.must("Ada", |name: &str| !name.is_empty()));
+///
+/// for result in scope.results() {
+///     match result {
+///         RuleResult::Passed => {}
+///         RuleResult::Failed(error) => println!("failed: {}", error),
+///         RuleResult::Skipped(skipped) => println!("{}", skipped),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub enum RuleResult {
+    /// The rule was evaluated and held.
+    Passed,
+    /// The rule was evaluated and didn't hold.
+    Failed(ConstraintError),
+    /// The rule wasn't evaluated because the deadline of its scope had passed.
+    Skipped(SkippedRule),
+}
+
+impl RuleResult {
+    /// Returns `true` if the rule was evaluated and held.
+    pub fn is_passed(&self) -> bool {
+        matches!(self, RuleResult::Passed)
+    }
+
+    /// Returns `true` if the rule was evaluated and didn't hold.
+    pub fn is_failed(&self) -> bool {
+        matches!(self, RuleResult::Failed(_))
+    }
+
+    /// Returns `true` if the rule was skipped after the deadline.
+    pub fn is_skipped(&self) -> bool {
+        matches!(self, RuleResult::Skipped(_))
+    }
+
+    /// Returns the error of a failed rule, if the rule failed.
+    pub fn failure(&self) -> Option<&ConstraintError> {
+        match self {
+            RuleResult::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns the skipped rule, if the rule was skipped.
+    pub fn skipped(&self) -> Option<&SkippedRule> {
+        match self {
+            RuleResult::Skipped(skipped) => Some(skipped),
+            _ => None,
+        }
+    }
+}
+
+impl From<Result<(), ConstraintError>> for RuleResult {
+    fn from(result: Result<(), ConstraintError>) -> Self {
+        match result {
+            Ok(()) => RuleResult::Passed,
+            Err(error) => RuleResult::Failed(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use std::panic::Location;
+
+    #[test]
+    fn should_convert_the_result_of_an_evaluated_rule() {
+        let failed = RuleResult::from(Err(ConstraintError::new(|| "Size".to_string())));
+
+        expect!(RuleResult::from(Ok(())).is_passed()).to(be_true());
+        expect!(failed.failure().map(ConstraintError::message))
+            .to(be_some().value("Size".to_string()));
+        expect!(failed.skipped()).to(be_none());
+    }
+
+    #[test]
+    fn should_tell_skipped_rules_apart_from_failures() {
+        let skipped = RuleResult::Skipped(SkippedRule::new("Size".to_string(), Location::caller()));
+
+        expect!(skipped.is_skipped()).to(be_true());
+        expect!(skipped.is_failed()).to(be_false());
+        expect!(skipped.failure()).to(be_none());
+    }
+}
@@ -0,0 +1,157 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Validation rules described as data, shared by the `wasm` bindings and the `config` loader.
+use crate::constraints::collections::be_empty::{BeEmpty, NotBeEmpty};
+use crate::constraints::collections::be_subset_of::{BeSubsetOf, BeSupersetOf};
+use crate::constraints::collections::contain_element::{ContainAll, ContainAny, ContainElement};
+use crate::constraints::collections::have_no_duplicates::HaveNoDuplicates;
+use crate::constraints::collections::have_size::HaveSize;
+use crate::constraints::constraint::{AsSubject, Constraint};
+use crate::constraints::numeric::be_in_range::BeInRange;
+use crate::constraints::strings::have_length::HaveLength;
+use crate::string_scope::StringScope;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// A single validation rule, as described in a JSON, TOML or YAML document.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Rule {
+    /// The message of the validation block the rule runs in.
+    pub(crate) message: String,
+    /// Whether the value must *not* satisfy the constraint, like `StringScope::must_not`.
+    #[serde(default)]
+    pub(crate) negate: bool,
+    #[serde(flatten)]
+    pub(crate) constraint: RuleConstraint,
+}
+
+/// The built-in constraints that can be described as data, tagged by the `constraint` field.
+///
+/// The length constraints check strings, `be_in_range` checks numbers and the rest check arrays.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "constraint", rename_all = "snake_case")]
+pub(crate) enum RuleConstraint {
+    HaveLength { length: usize },
+    MinLength { length: usize },
+    MaxLength { length: usize },
+    BeInRange { min: Option<f64>, max: Option<f64> },
+    HaveSize { size: usize },
+    MinSize { size: usize },
+    MaxSize { size: usize },
+    BeEmpty,
+    NotBeEmpty,
+    HaveNoDuplicates,
+    ContainElement { element: JsonElement },
+    ContainAll { elements: Vec<JsonElement> },
+    ContainAny { elements: Vec<JsonElement> },
+    BeSubsetOf { elements: Vec<JsonElement> },
+    BeSupersetOf { elements: Vec<JsonElement> },
+}
+
+/// An element of a JSON array, comparable and hashable by its JSON representation.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+pub(crate) struct JsonElement(pub(crate) Value);
+
+impl Hash for JsonElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_string().hash(state);
+    }
+}
+
+impl Debug for JsonElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Rule {
+    /// Records this rule for the given value in the scope.
+    ///
+    /// # Returns:
+    /// - `Ok(())`: If the rule was recorded.
+    /// - `Err(&str)`: The expected shape, like `expected a string`, if the value doesn't have the
+    ///   shape checked by the constraint. Nothing is recorded then.
+    pub(crate) fn apply(&self, scope: &StringScope, value: &Value) -> Result<(), &'static str> {
+        match &self.constraint {
+            RuleConstraint::HaveLength { length } => self.check(
+                scope,
+                as_str(value)?,
+                HaveLength::with_exact_length(*length),
+            ),
+            RuleConstraint::MinLength { length } => {
+                self.check(scope, as_str(value)?, HaveLength::with_min_length(*length))
+            }
+            RuleConstraint::MaxLength { length } => {
+                self.check(scope, as_str(value)?, HaveLength::with_max_length(*length))
+            }
+            RuleConstraint::BeInRange { min, max } => {
+                let number = value.as_f64().ok_or("expected a number")?;
+                let range = BeInRange {
+                    min: *min,
+                    max: *max,
+                };
+                self.check(scope, number, range)
+            }
+            RuleConstraint::HaveSize { size } => {
+                self.check(scope, as_array(value)?, HaveSize::with_exact_size(*size))
+            }
+            RuleConstraint::MinSize { size } => {
+                let size = *size;
+                self.check(scope, as_array(value)?, HaveSize::new(move |n| n >= size))
+            }
+            RuleConstraint::MaxSize { size } => {
+                let size = *size;
+                self.check(scope, as_array(value)?, HaveSize::new(move |n| n <= size))
+            }
+            RuleConstraint::BeEmpty => self.check(scope, as_array(value)?, BeEmpty),
+            RuleConstraint::NotBeEmpty => self.check(scope, as_array(value)?, NotBeEmpty),
+            RuleConstraint::HaveNoDuplicates => {
+                self.check(scope, as_array(value)?, HaveNoDuplicates)
+            }
+            RuleConstraint::ContainElement { element } => {
+                self.check(scope, as_array(value)?, ContainElement(element.clone()))
+            }
+            RuleConstraint::ContainAll { elements } => {
+                self.check(scope, as_array(value)?, ContainAll(elements.clone()))
+            }
+            RuleConstraint::ContainAny { elements } => {
+                self.check(scope, as_array(value)?, ContainAny(elements.clone()))
+            }
+            RuleConstraint::BeSubsetOf { elements } => {
+                self.check(scope, as_array(value)?, BeSubsetOf::new(elements.clone()))
+            }
+            RuleConstraint::BeSupersetOf { elements } => {
+                self.check(scope, as_array(value)?, BeSupersetOf::new(elements.clone()))
+            }
+        }
+        Ok(())
+    }
+
+    /// Records a `must` or `must_not` rule for the value, depending on `negate`.
+    fn check<V, T, C>(&self, scope: &StringScope, value: V, constraint: C)
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+    {
+        if self.negate {
+            scope.must_not(value, constraint);
+        } else {
+            scope.must(value, constraint);
+        }
+    }
+}
+
+/// Returns the elements of a JSON array, or the expected shape if the value is not an array.
+fn as_array(value: &Value) -> Result<Vec<JsonElement>, &'static str> {
+    Vec::<JsonElement>::deserialize(value).map_err(|_| "expected an array")
+}
+
+/// Returns the string of a JSON value, or the expected shape if it is not a string.
+fn as_str(value: &Value) -> Result<&str, &'static str> {
+    value.as_str().ok_or("expected a string")
+}
@@ -0,0 +1,1355 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::errors::composite_error::CompositeError;
+use crate::errors::constraint_error::ConstraintError;
+use crate::errors::failure_record::FailureRecord;
+use crate::errors::skipped_rule::SkippedRule;
+use crate::rule_result::RuleResult;
+use crate::string_scope::{BlockResult, StringScope};
+use crate::summary::Summary;
+use crate::validate::Validate;
+use crate::validation_observer::ValidationObserver;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// The default maximum number of nested values validated through `must_be_valid`.
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+/// The context objects attached to a scope, keyed by their type.
+pub(crate) type Contexts = Arc<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>;
+
+/// The root scope of a validation run.
+///
+/// `RustrictScope` owns the results of every validation block executed through it. Each block is
+/// run inside a `StringScope` labelled with the block's message, and all of them record their
+/// outcomes into the same shared container. This mirrors the top-level `constrainedBy { ... }`
+/// block of the Kotlin DSL.
+///
+/// # Fields:
+/// - `results`: A shared, thread-safe container with the outcome of every rule run so far,
+///   including the rules skipped after the deadline.
+/// - `budget`: The time the rules may take, from the creation of the scope or its last `reset`.
+/// - `deadline`: An optional instant after which the remaining rules are skipped.
+/// - `observers`: The observers notified of the outcome of every rule.
+/// - `warnings`: A shared, thread-safe container with the failures of the soft rules.
+/// - `blocks`: The outcome of each validation block, labelled with the block's message.
+/// - `contexts`: The context objects available to the rules, keyed by their type.
+/// - `max_depth`: The maximum number of nested values validated through `must_be_valid`.
+/// - `nesting`: The address and type of each value being validated through `must_be_valid`.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// let scope = RustrictScope::new();
+/// scope.validate_string("Roles must not be empty", |s| s.must(vec!["admin"], NotBeEmpty));
+/// assert!(scope.is_valid());
+/// ```
+pub struct RustrictScope {
+    results: Arc<Mutex<Vec<RuleResult>>>,
+    budget: Option<Duration>,
+    deadline: Mutex<Option<Instant>>,
+    observers: Vec<Arc<dyn ValidationObserver>>,
+    warnings: Arc<Mutex<Vec<ConstraintError>>>,
+    blocks: Mutex<Vec<BlockResult>>,
+    contexts: Contexts,
+    max_depth: usize,
+    nesting: Vec<(usize, &'static str)>,
+}
+
+impl RustrictScope {
+    /// Creates a new `RustrictScope` with no recorded results.
+    pub fn new() -> Self {
+        Self {
+            results: Arc::new(Mutex::new(Vec::new())),
+            budget: None,
+            deadline: Mutex::new(None),
+            observers: Vec::new(),
+            warnings: Arc::new(Mutex::new(Vec::new())),
+            blocks: Mutex::new(Vec::new()),
+            contexts: Contexts::default(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            nesting: Vec::new(),
+        }
+    }
+
+    /// Sets a time budget for the rules of this scope.
+    ///
+    /// Once the budget is spent, the remaining rules are not evaluated and are recorded as
+    /// `RuleResult::Skipped` instead, which keeps expensive constraints on untrusted input from
+    /// stalling the caller. A rule that is already running is not interrupted. Skipped rules are not
+    /// failures, but they keep the scope from being valid, since the values they would have
+    /// checked are unknown.
+    ///
+    /// # Parameters:
+    /// - `budget`: The time the rules of this scope may take, starting now and again on every
+    ///   `reset`.
+    ///
+    /// # Returns:
+    /// The same `RustrictScope`, with its deadline set.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # use std::time::Duration;
+    /// # let name = vec!["admin"];
+    /// # let user_pattern = NotBeEmpty;
+    /// let scope = RustrictScope::new().with_deadline(Duration::from_millis(50)).with_max_depth(8);
+    /// scope.validate_string("Name must match the pattern", |s| s.must(name, user_pattern));
+    /// if scope.skipped_count() > 0 {
+    ///     // The validation ran out of time.
+    /// }
+    /// ```
+    pub fn with_deadline(mut self, budget: Duration) -> Self {
+        self.budget = Some(budget);
+        self.deadline = Mutex::new(Some(Instant::now() + budget));
+        self
+    }
+
+    /// Returns the instant after which the remaining rules are skipped, if any.
+    fn deadline(&self) -> Option<Instant> {
+        *self.deadline.lock().unwrap()
+    }
+
+    /// Sets the maximum number of nested values validated through `must_be_valid`.
+    ///
+    /// Deeper values are not validated; a failure naming the type of the first value past the
+    /// limit is recorded instead. This bounds the stack used by deep or adversarial object graphs.
+    /// The default is `DEFAULT_MAX_DEPTH`.
+    ///
+    /// # Parameters:
+    /// - `max_depth`: The maximum nesting depth, counting the value validated first as depth 1.
+    ///
+    /// # Returns:
+    /// The same `RustrictScope`, with its maximum depth set.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Attaches a context object that the rules of this scope can read.
+    ///
+    /// Contexts carry request-wide data, like the current locale, the limits of a tenant or a
+    /// snapshot of feature flags, to rules declared far from the call site, which would otherwise
+    /// have to capture it. They play the role of a Kotlin receiver or `CoroutineContext` element.
+    /// Rules read them through `StringScope::context` or `StringScope::must_in_context`.
+    ///
+    /// Contexts are keyed by their type, so a scope holds at most one context of each type; a new
+    /// context replaces the previous one of the same type.
+    ///
+    /// # Parameters:
+    /// - `context`: The context object.
+    ///
+    /// # Returns:
+    /// The same `RustrictScope`, with the context attached.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// struct Locale(&'static str);
+    ///
+    /// let scope = RustrictScope::new().with_context(Locale("es-CL"));
+    /// assert_eq!(scope.context::<Locale>().unwrap().0, "es-CL");
+    /// ```
+    pub fn with_context<C: Any + Send + Sync>(mut self, context: C) -> Self {
+        Arc::make_mut(&mut self.contexts).insert(TypeId::of::<C>(), Arc::new(context));
+        self
+    }
+
+    /// Returns the context object of the given type attached to this scope, if any.
+    pub fn context<C: Any>(&self) -> Option<&C> {
+        self.contexts.get(&TypeId::of::<C>())?.downcast_ref()
+    }
+
+    /// Registers an observer notified of the outcome of every rule run from now on.
+    ///
+    /// # Parameters:
+    /// - `observer`: The observer to notify. Keep a clone of the `Arc` to read its state later.
+    pub fn register_observer(&mut self, observer: Arc<dyn ValidationObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Runs a validation block labelled with the given message.
+    ///
+    /// # Parameters:
+    /// - `message`: The message used for the errors generated by the rules of the block.
+    /// - `block`: A closure that declares the rules through the provided `StringScope`.
+    ///
+    /// The outcome of the block's rules can later be checked on its own through `block_result`.
+    ///
+    /// With the `tracing` feature enabled, the block runs inside a `validate_string` span.
+    pub fn validate_string<F, R>(&self, message: impl Into<String>, block: F)
+    where
+        F: FnOnce(&StringScope) -> R,
+    {
+        let message = message.into();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("validate_string", message = %message).entered();
+        let scope = StringScope::new(message, self.results.clone())
+            .with_deadline(self.deadline())
+            .with_observers(self.observers.clone())
+            .with_warnings(self.warnings.clone())
+            .with_contexts(self.contexts.clone());
+        block(&scope);
+        self.blocks.lock().unwrap().push(scope.into_block_result());
+    }
+
+    /// Runs a validation block whose failures are created by a custom exception generator instead
+    /// of the constraints' own errors.
+    ///
+    /// # Parameters:
+    /// - `message`: The message passed to the exception generator.
+    /// - `exception_generator`: A closure that creates the error for a failed rule.
+    /// - `block`: A closure that declares the rules through the provided `StringScope`.
+    ///
+    /// With the `tracing` feature enabled, the block runs inside a `validate_string` span.
+    pub fn validate_string_with_custom_exception<G, F, R>(
+        &self,
+        message: impl Into<String>,
+        exception_generator: G,
+        block: F,
+    ) where
+        G: Fn(String) -> ConstraintError + 'static,
+        F: FnOnce(&StringScope) -> R,
+    {
+        let message = message.into();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("validate_string", message = %message).entered();
+        let scope = StringScope::new_with_exception_generator(
+            message,
+            self.results.clone(),
+            Box::new(exception_generator),
+        )
+        .with_deadline(self.deadline())
+        .with_observers(self.observers.clone())
+        .with_warnings(self.warnings.clone())
+        .with_contexts(self.contexts.clone());
+        block(&scope);
+        self.blocks.lock().unwrap().push(scope.into_block_result());
+    }
+
+    /// Validates a nested value by running its rules within this scope.
+    ///
+    /// The failures of the nested value are recorded alongside the ones of this scope, which makes
+    /// it possible to compose validations across a domain model.
+    ///
+    /// Self-referential object graphs are guarded against: a value that is already being
+    /// validated, identified by its address and type, is not validated again, and neither is a
+    /// value nested deeper than the maximum depth (see `with_max_depth`). Each case records a
+    /// failure instead of overflowing the stack.
+    ///
+    /// # Parameters:
+    /// - `child`: The value whose constraints must hold.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # struct Address;
+    /// # impl Validate for Address {
+    /// #     fn constraints(&self, _scope: &mut RustrictScope) {}
+    /// # }
+    /// # struct User { roles: Vec<String>, address: Address }
+    /// impl Validate for User {
+    ///     fn constraints(&self, scope: &mut RustrictScope) {
+    ///         scope.validate_string("Roles must not be empty", |s| s.must(self.roles.clone(), NotBeEmpty));
+    ///         scope.must_be_valid(&self.address);
+    ///     }
+    /// }
+    /// ```
+    pub fn must_be_valid<V>(&mut self, child: &V)
+    where
+        V: Validate + ?Sized,
+    {
+        let identity = (child as *const V as *const () as usize, std::any::type_name::<V>());
+        let problem = if self.nesting.contains(&identity) {
+            Some(format!("Cycle detected: {} is already being validated", identity.1))
+        } else if self.nesting.len() >= self.max_depth {
+            Some(format!(
+                "Maximum nesting depth of {} exceeded by {}",
+                self.max_depth, identity.1
+            ))
+        } else {
+            None
+        };
+        match problem {
+            Some(message) => self.validate_string(message, |s| s.constraint(|| false)),
+            None => child.constraints(&mut NestingGuard::enter(self, identity)),
+        }
+    }
+
+    /// Returns a copy of the results recorded so far, including the rules skipped after the
+    /// deadline.
+    ///
+    /// This clones the whole results vector; prefer `take_results`, `iter_failures`,
+    /// `failure_count` or `is_valid` when validating large inputs.
+    pub fn results(&self) -> Vec<RuleResult> {
+        self.results.lock().unwrap().clone()
+    }
+
+    /// Returns a copy of every error recorded so far.
+    pub fn failures(&self) -> Vec<ConstraintError> {
+        self.iter_failures().iter().cloned().collect()
+    }
+
+    /// Returns the outcome of the blocks run with the given message.
+    ///
+    /// This tells whether a single block passed, independently of the rest of the validation. If
+    /// several blocks share the message, their results are combined in the order they ran.
+    ///
+    /// # Parameters:
+    /// - `message`: The message the blocks were run with.
+    ///
+    /// # Returns:
+    /// - `Some(BlockResult)`: With the results of the rules of the matching blocks.
+    /// - `None`: If no block was run with that message.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let scope = RustrictScope::new();
+    /// # let username = vec!['a', 'd', 'a'];
+    /// scope.validate_string("Username must not repeat characters", |s| {
+    ///     s.must(username, HaveNoDuplicates)
+    /// });
+    /// let block = scope.block_result("Username must not repeat characters").unwrap();
+    /// assert!(!block.is_valid());
+    /// ```
+    pub fn block_result(&self, message: &str) -> Option<BlockResult> {
+        self.blocks
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|block| block.message() == message)
+            .cloned()
+            .reduce(BlockResult::merge)
+    }
+
+    /// Returns the outcome of every block run so far, in the order they ran.
+    pub fn block_results(&self) -> Vec<BlockResult> {
+        self.blocks.lock().unwrap().clone()
+    }
+
+    /// Returns the failures recorded so far, grouped by the message of the block that produced
+    /// them.
+    ///
+    /// Blocks that share a message are grouped together, and the failures of each group keep the
+    /// order in which they were recorded. Messages whose rules all passed are left out, so the
+    /// result can be rendered directly as one section per invalid field, much like Kotlin's
+    /// `groupBy`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let scope = RustrictScope::new();
+    /// scope.validate_string("Roles", |s| {
+    ///     s.must(Vec::<&str>::new(), NotBeEmpty);
+    ///     s.must(vec!["admin", "admin"], HaveNoDuplicates);
+    /// });
+    /// scope.validate_string("Email", |s| s.must("a@b.cl", MatchGlob::new("*@*")));
+    ///
+    /// let failures = scope.failures_by_message();
+    /// assert_eq!(failures["Roles"].len(), 2);
+    /// assert!(!failures.contains_key("Email"));
+    /// ```
+    pub fn failures_by_message(&self) -> HashMap<String, Vec<ConstraintError>> {
+        let mut groups: HashMap<String, Vec<ConstraintError>> = HashMap::new();
+        for block in self.blocks.lock().unwrap().iter() {
+            let mut failures = block.failures().cloned().peekable();
+            if failures.peek().is_some() {
+                groups.entry(block.message().to_string()).or_default().extend(failures);
+            }
+        }
+        groups
+    }
+
+    /// Returns the failures recorded so far, grouped by the path of the field that failed.
+    ///
+    /// Only errors that record a field, see `ConstraintError::with_field`, are included; the
+    /// failures of each field keep the order in which they were recorded.
+    pub fn failures_by_field(&self) -> HashMap<String, Vec<ConstraintError>> {
+        group_by_field(self.iter_failures().iter())
+    }
+
+    /// Returns a copy of every warning recorded so far by soft rules (`should` and `should_not`).
+    ///
+    /// Warnings are kept apart from the results: they don't count as failures and don't make the
+    /// scope invalid.
+    pub fn warnings(&self) -> Vec<ConstraintError> {
+        self.warnings.lock().unwrap().clone()
+    }
+
+    /// Returns the number of soft rules that failed so far.
+    pub fn warning_count(&self) -> usize {
+        self.warnings.lock().unwrap().len()
+    }
+
+    /// Returns a copy of every rule skipped so far because the deadline had passed.
+    ///
+    /// These are the `RuleResult::Skipped` entries of the results, in the order they were
+    /// recorded.
+    pub fn skipped(&self) -> Vec<SkippedRule> {
+        self.results
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(RuleResult::skipped)
+            .cloned()
+            .collect()
+    }
+
+    /// Moves the recorded results out of the scope, leaving it empty.
+    ///
+    /// Unlike `results`, no result is cloned. The warnings and block outcomes are discarded as
+    /// well, like in `reset`, so that every view of the scope agrees that it is empty.
+    pub fn take_results(&self) -> Vec<RuleResult> {
+        let results = std::mem::take(&mut *self.results.lock().unwrap());
+        self.reset();
+        results
+    }
+
+    /// Discards every recorded result, warning and block outcome, keeping the scope ready for the next
+    /// validation.
+    ///
+    /// The results' allocation is kept, so a scope can be reused across the iterations of a
+    /// streaming job instead of creating a new one per record. Registered observers are kept as
+    /// well, and the time budget set through `with_deadline` starts over, so that every record gets
+    /// the whole budget.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let records = vec![vec!["admin"], vec![]];
+    /// let scope = RustrictScope::new();
+    /// for record in records {
+    ///     scope.validate_string("Roles must not be empty", |s| s.must(record, NotBeEmpty));
+    ///     if !scope.is_valid() {
+    ///         // Report the record.
+    ///     }
+    ///     scope.reset();
+    /// }
+    /// ```
+    pub fn reset(&self) {
+        self.results.lock().unwrap().clear();
+        self.warnings.lock().unwrap().clear();
+        self.blocks.lock().unwrap().clear();
+        *self.deadline.lock().unwrap() = self.budget.map(|budget| Instant::now() + budget);
+    }
+
+    /// Marks the current point of the validation, to later discard what is recorded after it.
+    ///
+    /// # Returns:
+    /// A `Checkpoint` to pass to `rollback`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: self.results.lock().unwrap().len(),
+            warnings: self.warning_count(),
+            blocks: self.blocks.lock().unwrap().len(),
+        }
+    }
+
+    /// Discards the results, warnings and block outcomes recorded since the given checkpoint.
+    ///
+    /// This lets a speculative branch of the validation be undone, e.g. when trying alternative
+    /// shapes of a value. Checkpoints taken before a `reset` or `take_results` may point past the
+    /// recorded results, in which case nothing is discarded.
+    ///
+    /// # Parameters:
+    /// - `checkpoint`: A checkpoint obtained from this scope.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let scope = RustrictScope::new();
+    /// let checkpoint = scope.checkpoint();
+    /// scope.validate_string("Roles must be a pair", |s| s.must(vec!["admin"], HaveSize::with_exact_size(2)));
+    /// scope.rollback(checkpoint);
+    /// assert!(scope.is_valid());
+    /// ```
+    pub fn rollback(&self, checkpoint: Checkpoint) {
+        self.results.lock().unwrap().truncate(checkpoint.len);
+        self.warnings.lock().unwrap().truncate(checkpoint.warnings);
+        self.blocks.lock().unwrap().truncate(checkpoint.blocks);
+    }
+
+    /// Returns a borrowed view over the errors recorded so far.
+    ///
+    /// The view keeps the results locked until it is dropped, so rules must not be recorded
+    /// through this scope while it is alive.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let scope = RustrictScope::new();
+    /// for failure in &scope.iter_failures() {
+    ///     println!("{}", failure);
+    /// }
+    /// ```
+    pub fn iter_failures(&self) -> Failures<'_> {
+        Failures {
+            results: self.results.lock().unwrap(),
+        }
+    }
+
+    /// Returns a `FailureRecord` for each failed rule, in the order they were recorded.
+    ///
+    /// Unlike `failures`, the records hold rendered messages and plain strings only, so they can
+    /// be stored, compared or sent to audit and logging systems.
+    pub fn failure_records(&self) -> Vec<FailureRecord> {
+        self.iter_failures().iter().map(ConstraintError::record).collect()
+    }
+
+    /// Returns the number of failed rules without cloning any result.
+    pub fn failure_count(&self) -> usize {
+        self.results
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|result| result.is_failed())
+            .count()
+    }
+
+    /// Returns the number of rules that were skipped because the deadline had passed.
+    ///
+    /// Skipped rules are not counted by `failure_count`.
+    pub fn skipped_count(&self) -> usize {
+        self.results
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|result| result.is_skipped())
+            .count()
+    }
+
+    /// Returns the counts of evaluated, passed, failed and skipped rules, overall and per block
+    /// message.
+    ///
+    /// The results are counted in place, without cloning them, so a summary is cheap to take
+    /// even after millions of rules.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// let scope = RustrictScope::new();
+    /// scope.validate_string("Roles", |s| s.must(Vec::<&str>::new(), NotBeEmpty));
+    ///
+    /// let summary = scope.summary();
+    /// assert_eq!((summary.evaluated(), summary.failed()), (1, 1));
+    /// ```
+    pub fn summary(&self) -> Summary {
+        Summary::new(&self.results.lock().unwrap(), &self.blocks.lock().unwrap())
+    }
+
+    /// Returns `true` if no rule has failed or been skipped so far.
+    pub fn is_valid(&self) -> bool {
+        self.results.lock().unwrap().iter().all(RuleResult::is_passed)
+    }
+
+    /// Consumes the scope, aggregating every failure into a `CompositeError`.
+    ///
+    /// # Returns:
+    /// - `Ok(())`: If no rule failed or was skipped.
+    /// - `Err(CompositeError)`: With the recorded errors, in the order they were recorded,
+    ///   followed by a `SkippedRule` for each rule skipped after the deadline.
+    pub fn into_result(self) -> Result<(), CompositeError> {
+        aggregate(self.take_results())
+    }
+
+    /// Aggregates the failures recorded so far into a `CompositeError`, keeping the scope usable.
+    ///
+    /// Unlike `into_result`, the scope is neither consumed nor cleared, so a function can
+    /// interleave validation with business logic and return early with `?` at any point, much like
+    /// calling Kotlin's `check` between statements. Rules declared afterwards keep accumulating,
+    /// and a later call reports them together with the earlier failures. The `check!` macro is a
+    /// shorthand for `scope.checked()?`.
+    ///
+    /// # Returns:
+    /// - `Ok(())`: If no rule has failed or been skipped so far.
+    /// - `Err(CompositeError)`: With the errors recorded so far, in the order they were recorded,
+    ///   followed by the rules skipped so far.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// fn register(roles: Vec<&str>) -> Result<usize, CompositeError> {
+    ///     let scope = RustrictScope::new();
+    ///     scope.validate_string("Roles must not be empty", |s| s.must(roles.clone(), NotBeEmpty));
+    ///     scope.checked()?;
+    ///     let admins = roles.iter().filter(|role| **role == "admin").count();
+    ///     scope.validate_string("There must be a single admin", |s| s.constraint(|| admins == 1));
+    ///     scope.into_result().map(|_| admins)
+    /// }
+    ///
+    /// assert!(register(vec![]).is_err());
+    /// assert_eq!(register(vec!["admin", "viewer"]).unwrap(), 1);
+    /// ```
+    pub fn checked(&self) -> Result<(), CompositeError> {
+        aggregate(self.results())
+    }
+
+    /// Lazily validates the items of an iterator, each in its own scope.
+    ///
+    /// Items are pulled one at a time and their results are dropped once yielded, so inputs far
+    /// larger than memory can be validated. Every item scope keeps the deadline, the observers and
+    /// the contexts of this scope, but nothing is recorded in this scope itself.
+    ///
+    /// # Parameters:
+    /// - `items`: The items to validate.
+    /// - `rules`: A closure that declares the rules of an item through its scope.
+    ///
+    /// # Returns:
+    /// An iterator with the outcome of each item, in the order of `items`.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let rows = vec![vec!["a"], vec![], vec![]];
+    /// let scope = RustrictScope::new();
+    /// let outcomes = scope
+    ///     .validate_iter(rows, |row_scope, row| {
+    ///         row_scope.validate_string("Row must not be empty", |s| s.must(row, NotBeEmpty));
+    ///     })
+    ///     .stop_after(1);
+    /// for (line, outcome) in outcomes.enumerate() {
+    ///     if let Err(error) = outcome {
+    ///         println!("line {}: {}", line, error);
+    ///     }
+    /// }
+    /// ```
+    pub fn validate_iter<I, F>(&self, items: I, rules: F) -> ValidateIter<'_, I::IntoIter, F>
+    where
+        I: IntoIterator,
+        F: FnMut(&mut RustrictScope, I::Item),
+    {
+        ValidateIter {
+            parent: self,
+            items: items.into_iter(),
+            rules,
+            remaining_failures: None,
+        }
+    }
+
+    /// Validates the items of a slice across several threads.
+    ///
+    /// The items are split into contiguous chunks, one per available core, and each chunk is
+    /// validated on its own thread within `std::thread::scope`. Each thread records into its own
+    /// scope, with the deadline, the observers and the contexts of this one, and the recorded
+    /// results, warnings and block outcomes are merged into this scope once every thread finished.
+    ///
+    /// # Ordering:
+    /// The merged results are in the order of `items`, and the results of each item are in the
+    /// order its rules ran, exactly as if the items had been validated sequentially. Observers,
+    /// on the other hand, are notified from the worker threads as rules run, so the order of the
+    /// notifications of different items is unspecified.
+    ///
+    /// # Parameters:
+    /// - `items`: The items to validate.
+    /// - `rules`: A closure that declares the rules of an item through the provided scope.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let teams = vec![vec!["ana"], vec![]];
+    /// let scope = RustrictScope::new();
+    /// scope.validate_concurrently(&teams, |scope, team| {
+    ///     scope.validate_string("Team must not be empty", |s| s.must(team.clone(), NotBeEmpty));
+    /// });
+    /// assert_eq!(scope.failure_count(), 1);
+    /// ```
+    pub fn validate_concurrently<T, F>(&self, items: &[T], rules: F)
+    where
+        T: Sync,
+        F: Fn(&mut RustrictScope, &T) + Sync,
+    {
+        if items.is_empty() {
+            return;
+        }
+        let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+        let chunk_size = items.len().div_ceil(threads);
+        let rules = &rules;
+        let scopes: Vec<RustrictScope> = std::thread::scope(|threads| {
+            let handles: Vec<_> = items
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    let mut scope = self.child();
+                    threads.spawn(move || {
+                        for item in chunk {
+                            rules(&mut scope, item);
+                        }
+                        scope
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        });
+        for scope in scopes {
+            self.merge(scope);
+        }
+    }
+
+    /// Appends everything recorded in another scope to this one.
+    fn merge(&self, other: RustrictScope) {
+        self.results.lock().unwrap().append(&mut other.results.lock().unwrap());
+        self.warnings.lock().unwrap().append(&mut other.warnings.lock().unwrap());
+        self.blocks.lock().unwrap().append(&mut other.blocks.lock().unwrap());
+    }
+
+    /// Creates an empty scope with the deadline, observers, contexts and maximum depth of this one.
+    fn child(&self) -> RustrictScope {
+        RustrictScope {
+            budget: self.budget,
+            deadline: Mutex::new(self.deadline()),
+            observers: self.observers.clone(),
+            contexts: self.contexts.clone(),
+            max_depth: self.max_depth,
+            ..RustrictScope::new()
+        }
+    }
+}
+
+/// A value being validated through `must_be_valid`, popped from the nesting stack of its scope
+/// when the guard is dropped.
+///
+/// Popping on drop keeps the stack accurate even if the rules of the value panic and the panic is
+/// caught further up, so the value isn't mistaken for a cycle when validated again.
+struct NestingGuard<'a> {
+    scope: &'a mut RustrictScope,
+}
+
+impl<'a> NestingGuard<'a> {
+    /// Pushes the identity of a value onto the nesting stack of the scope.
+    fn enter(scope: &'a mut RustrictScope, identity: (usize, &'static str)) -> Self {
+        scope.nesting.push(identity);
+        Self { scope }
+    }
+}
+
+impl Deref for NestingGuard<'_> {
+    type Target = RustrictScope;
+
+    fn deref(&self) -> &RustrictScope {
+        self.scope
+    }
+}
+
+impl DerefMut for NestingGuard<'_> {
+    fn deref_mut(&mut self) -> &mut RustrictScope {
+        self.scope
+    }
+}
+
+impl Drop for NestingGuard<'_> {
+    fn drop(&mut self) {
+        self.scope.nesting.pop();
+    }
+}
+
+/// Groups the errors that record a field by the path of that field.
+fn group_by_field<'a>(
+    errors: impl Iterator<Item = &'a ConstraintError>,
+) -> HashMap<String, Vec<ConstraintError>> {
+    let mut groups: HashMap<String, Vec<ConstraintError>> = HashMap::new();
+    for error in errors {
+        if let Some(field) = error.field() {
+            groups.entry(field.to_string()).or_default().push(error.clone());
+        }
+    }
+    groups
+}
+
+/// Aggregates the failed and skipped results into a `CompositeError`, if there is any.
+///
+/// The failures come first, followed by the skipped rules, each group in recording order.
+fn aggregate(results: Vec<RuleResult>) -> Result<(), CompositeError> {
+    let mut errors: Vec<ConstraintError> = Vec::new();
+    let mut skipped: Vec<SkippedRule> = Vec::new();
+    for result in results {
+        match result {
+            RuleResult::Passed => {}
+            RuleResult::Failed(error) => errors.push(error),
+            RuleResult::Skipped(rule) => skipped.push(rule),
+        }
+    }
+    let composite = match (errors.is_empty(), skipped.is_empty()) {
+        (true, true) => return Ok(()),
+        (true, false) => return Err(CompositeError::from_errors(skipped)),
+        (false, _) => CompositeError::from_errors(errors),
+    };
+    Err(skipped.into_iter().fold(composite, CompositeError::with_error))
+}
+
+impl Default for RustrictScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A lazy iterator over the outcomes of validating each item of another iterator.
+///
+/// Obtained through `RustrictScope::validate_iter`.
+pub struct ValidateIter<'a, I, F> {
+    parent: &'a RustrictScope,
+    items: I,
+    rules: F,
+    remaining_failures: Option<usize>,
+}
+
+impl<I, F> ValidateIter<'_, I, F> {
+    /// Stops the iteration once the given number of items failed.
+    ///
+    /// The last yielded outcome is the last failure; the remaining items are not pulled.
+    ///
+    /// # Parameters:
+    /// - `failures`: The number of failed items after which no more items are validated.
+    pub fn stop_after(mut self, failures: usize) -> Self {
+        self.remaining_failures = Some(failures);
+        self
+    }
+}
+
+impl<I, F> Iterator for ValidateIter<'_, I, F>
+where
+    I: Iterator,
+    F: FnMut(&mut RustrictScope, I::Item),
+{
+    type Item = Result<(), CompositeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_failures == Some(0) {
+            return None;
+        }
+        let item = self.items.next()?;
+        let mut scope = self.parent.child();
+        (self.rules)(&mut scope, item);
+        let outcome = scope.into_result();
+        if outcome.is_err() {
+            self.remaining_failures = self.remaining_failures.map(|remaining| remaining - 1);
+        }
+        Some(outcome)
+    }
+}
+
+/// A point in the results of a `RustrictScope`, obtained through `RustrictScope::checkpoint`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    len: usize,
+    warnings: usize,
+    blocks: usize,
+}
+
+/// A borrowed view over the errors recorded in a `RustrictScope`.
+///
+/// Obtained through `RustrictScope::iter_failures`. The scope's results stay locked while the view
+/// is alive, which is what allows iterating them by reference instead of cloning them.
+pub struct Failures<'a> {
+    results: MutexGuard<'a, Vec<RuleResult>>,
+}
+
+impl Failures<'_> {
+    /// Returns an iterator over the recorded errors, in the order they were recorded.
+    pub fn iter(&self) -> impl Iterator<Item = &ConstraintError> {
+        self.results.iter().filter_map(RuleResult::failure)
+    }
+}
+
+impl<'a> IntoIterator for &'a Failures<'_> {
+    type Item = &'a ConstraintError;
+    type IntoIter = Box<dyn Iterator<Item = &'a ConstraintError> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::have_size::HaveSize;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+    use crate::testing::validate_sizes;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    proptest! {
+        #[test]
+        fn should_record_a_result_per_rule(sizes in proptest::collection::vec(0usize..6, 0..20)) {
+            let scope = RustrictScope::new();
+            validate_sizes(&scope, &sizes);
+
+            let expected_failures = sizes.iter().filter(|size| **size != 3).count();
+            expect!(scope.results().len()).to(be_equal_to(sizes.len()));
+            expect!(scope.failures().len()).to(be_equal_to(expected_failures));
+            expect!(scope.failure_count()).to(be_equal_to(expected_failures));
+            expect!(scope.is_valid()).to(be_equal_to(expected_failures == 0));
+        }
+
+        #[test]
+        fn should_iterate_failures_in_recording_order(sizes in proptest::collection::vec(0usize..6, 0..20)) {
+            let scope = RustrictScope::new();
+            validate_sizes(&scope, &sizes);
+
+            let messages: Vec<String> = scope.iter_failures().iter().map(|e| e.message()).collect();
+            let expected: Vec<String> = sizes
+                .iter()
+                .filter(|size| **size != 3)
+                .map(|size| format!("Size must be {}: expected size {}, got 3", size, size))
+                .collect();
+            expect!(messages).to(be_equal_to(expected));
+        }
+
+        #[test]
+        fn should_leave_the_scope_empty_after_taking_the_results(
+            sizes in proptest::collection::vec(0usize..6, 0..20)
+        ) {
+            let scope = RustrictScope::new();
+            validate_sizes(&scope, &sizes);
+
+            let results = scope.take_results();
+
+            expect!(results.len()).to(be_equal_to(sizes.len()));
+            expect!(scope.results().len()).to(be_equal_to(0));
+            expect!(scope.is_valid()).to(be_true());
+            expect!(scope.block_results().len()).to(be_equal_to(0));
+            expect!(scope.summary()).to(be_equal_to(Summary::default()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn should_aggregate_the_failures_into_a_composite_error(
+            sizes in proptest::collection::vec(0usize..6, 0..20)
+        ) {
+            let scope = RustrictScope::new();
+            validate_sizes(&scope, &sizes);
+            let expected_failures = scope.failure_count();
+
+            let failures = match scope.into_result() {
+                Ok(()) => 0,
+                Err(error) => error.errors().len(),
+            };
+            expect!(failures).to(be_equal_to(expected_failures));
+        }
+    }
+
+    #[test]
+    fn should_use_the_custom_exception_generator() {
+        let scope = RustrictScope::new();
+        scope.validate_string_with_custom_exception(
+            "Size must be 2",
+            |message| ConstraintError::new(move || format!("Custom: {}", message)),
+            |s| s.must(vec![1, 2, 3], HaveSize::with_exact_size(2)),
+        );
+
+        expect!(scope.failures()[0].message()).to(be_equal_to("Custom: Size must be 2"));
+    }
+
+    #[test]
+    fn should_skip_the_rules_after_the_deadline() {
+        let scope = RustrictScope::new().with_deadline(Duration::ZERO);
+        validate_sizes(&scope, &[3, 2]);
+
+        expect!(scope.skipped_count()).to(be_equal_to(2));
+        expect!(scope.results().iter().all(RuleResult::is_skipped)).to(be_true());
+        expect!(scope.failure_count()).to(be_equal_to(0));
+        expect!(scope.is_valid()).to(be_false());
+        expect!(scope.skipped()[0].to_string())
+            .to(be_equal_to("Size must be 3: skipped after the deadline passed"));
+        expect!(scope.block_result("Size must be 2").unwrap().skipped().count()).to(be_equal_to(1));
+    }
+
+    #[test]
+    fn should_report_the_skipped_rules_after_the_failures() {
+        let scope = RustrictScope::new().with_deadline(Duration::from_secs(3600));
+        validate_sizes(&scope, &[2]);
+        let scope = RustrictScope {
+            deadline: Mutex::new(Some(Instant::now())),
+            ..scope
+        };
+        validate_sizes(&scope, &[3]);
+
+        let error = scope.into_result().unwrap_err();
+
+        expect!(error.type_names()).to(be_equal_to(
+            [
+                std::any::type_name::<ConstraintError>(),
+                std::any::type_name::<SkippedRule>(),
+            ]
+            .as_slice(),
+        ));
+    }
+
+    #[test]
+    fn should_restart_the_time_budget_after_a_reset() {
+        let scope = RustrictScope::new().with_deadline(Duration::from_secs(3600));
+        *scope.deadline.lock().unwrap() = Some(Instant::now());
+        validate_sizes(&scope, &[3]);
+        expect!(scope.skipped_count()).to(be_equal_to(1));
+
+        scope.reset();
+        validate_sizes(&scope, &[3]);
+
+        expect!(scope.skipped_count()).to(be_equal_to(0));
+        expect!(scope.is_valid()).to(be_true());
+    }
+
+    #[test]
+    fn should_run_the_rules_within_the_deadline() {
+        let scope = RustrictScope::new().with_deadline(Duration::from_secs(3600));
+        validate_sizes(&scope, &[3, 2]);
+
+        expect!(scope.skipped_count()).to(be_equal_to(0));
+        expect!(scope.failure_count()).to(be_equal_to(1));
+    }
+
+    proptest! {
+        #[test]
+        fn should_discard_the_results_recorded_after_a_checkpoint(
+            kept in proptest::collection::vec(0usize..6, 0..10),
+            discarded in proptest::collection::vec(0usize..6, 0..10)
+        ) {
+            let scope = RustrictScope::new();
+            validate_sizes(&scope, &kept);
+            let checkpoint = scope.checkpoint();
+            validate_sizes(&scope, &discarded);
+
+            scope.rollback(checkpoint);
+
+            expect!(scope.results().len()).to(be_equal_to(kept.len()));
+            expect!(scope.failure_count()).to(be_equal_to(kept.iter().filter(|s| **s != 3).count()));
+        }
+    }
+
+    #[test]
+    fn should_snapshot_each_failure_into_a_record() {
+        let scope = RustrictScope::new();
+        validate_sizes(&scope, &[3, 1]);
+        scope.validate_string("Level", |s| s.must_not_be_equal_to(2, 2));
+
+        let records = scope.failure_records();
+
+        expect!(records.len()).to(be_equal_to(2));
+        expect!(records[0].message()).to(be_equal_to("Size must be 1: expected size 1, got 3"));
+        expect!(records[0].rule().unwrap().ends_with("HaveSize")).to(be_true());
+        expect!(records[0].value()).to(be_none());
+        expect!(records[1].message()).to(be_equal_to("Level: expected NOT 2, got 2"));
+        expect!(records[1].value()).to(be_equal_to(Some("2")));
+        expect!(records[1].field()).to(be_none());
+    }
+
+    #[test]
+    fn should_share_its_contexts_with_every_rule() {
+        struct Limit(usize);
+        let scope = RustrictScope::new().with_context(Limit(2)).with_context(Limit(3));
+
+        validate_sizes(&scope, &[3]);
+        scope.validate_string("Within the limit", |s| {
+            s.must_in_context(vec![1, 2, 3], |value: &[i32], limit: &Limit| value.len() <= limit.0);
+            s.must_in_context(vec![1], |_: &[i32], _: &String| true);
+        });
+        let items: Vec<_> = scope
+            .validate_iter([4usize, 1], |item, size| {
+                item.validate_string("Item", |s| {
+                    s.constraint(|| s.context::<Limit>().is_some_and(|limit| size <= limit.0));
+                });
+            })
+            .collect();
+
+        expect!(scope.context::<Limit>().map(|limit| limit.0)).to(be_some().value(3));
+        expect!(scope.failures()[0].message().contains("missing context")).to(be_true());
+        expect!(scope.failure_count()).to(be_equal_to(1));
+        expect!(items[0].is_err() && items[1].is_ok()).to(be_true());
+    }
+
+    #[test]
+    fn should_group_the_failures_by_message_and_field() {
+        let scope = RustrictScope::new();
+        validate_sizes(&scope, &[1, 2, 3, 1]);
+        scope.validate_string_with_custom_exception(
+            "Email",
+            |message| ConstraintError::new(move || message.clone()).with_field("user.email"),
+            |s| s.must(Vec::<u8>::new(), HaveSize::with_exact_size(1)),
+        );
+
+        let by_message = scope.failures_by_message();
+        let by_field = scope.failures_by_field();
+
+        expect!(by_message.len()).to(be_equal_to(3));
+        expect!(by_message["Size must be 1"].len()).to(be_equal_to(2));
+        expect!(by_message.contains_key("Size must be 3")).to(be_false());
+        expect!(by_field.len()).to(be_equal_to(1));
+        expect!(by_field["user.email"][0].message()).to(be_equal_to("Email"));
+    }
+
+    #[test]
+    fn should_be_reusable_after_a_reset() {
+        let scope = RustrictScope::new();
+        validate_sizes(&scope, &[1, 2]);
+
+        scope.reset();
+        validate_sizes(&scope, &[3]);
+
+        expect!(scope.results().len()).to(be_equal_to(1));
+        expect!(scope.is_valid()).to(be_true());
+    }
+
+    #[test]
+    fn should_return_early_with_the_failures_so_far() {
+        fn run(scope: &RustrictScope, sizes: &[usize]) -> Result<usize, CompositeError> {
+            validate_sizes(scope, sizes);
+            crate::check!(scope);
+            validate_sizes(scope, &[1]);
+            scope.checked()?;
+            Ok(sizes.len())
+        }
+
+        let scope = RustrictScope::new();
+        expect!(run(&scope, &[3])).to(be_err());
+        expect!(scope.failure_count()).to(be_equal_to(1));
+
+        let scope = RustrictScope::new();
+        let error = run(&scope, &[1, 2]).unwrap_err();
+        expect!(error.errors().len()).to(be_equal_to(2));
+        expect!(scope.results().len()).to(be_equal_to(2));
+    }
+
+    #[test]
+    fn should_ignore_checkpoints_past_the_results() {
+        let scope = RustrictScope::new();
+        validate_sizes(&scope, &[1, 2]);
+        let checkpoint = scope.checkpoint();
+
+        scope.reset();
+        validate_sizes(&scope, &[1]);
+        scope.rollback(checkpoint);
+
+        expect!(scope.failure_count()).to(be_equal_to(1));
+    }
+
+    #[test]
+    fn should_keep_warnings_apart_from_failures() {
+        let scope = RustrictScope::new();
+        scope.validate_string("Tags should be a pair", |s| {
+            s.should(vec!["a"], HaveSize::with_exact_size(2));
+        });
+        let checkpoint = scope.checkpoint();
+        scope.validate_string("Tags should be a triple", |s| {
+            s.should(vec!["a"], HaveSize::with_exact_size(3));
+        });
+
+        expect!(scope.is_valid()).to(be_true());
+        expect!(scope.warning_count()).to(be_equal_to(2));
+        scope.rollback(checkpoint);
+        expect!(scope.warnings()[0].message())
+            .to(be_equal_to("Tags should be a pair: expected size 2, got 1"));
+        expect!(scope.warning_count()).to(be_equal_to(1));
+        expect!(scope.into_result()).to(be_ok());
+    }
+
+    #[test]
+    fn should_report_the_outcome_of_each_block() {
+        let scope = RustrictScope::new();
+
+        scope.validate_string("Username must be a pair", |s| {
+            s.must(vec!['a', 'b'], HaveSize::with_exact_size(2));
+        });
+        scope.validate_string("Roles must be a pair", |s| {
+            s.must(vec!["admin"], HaveSize::with_exact_size(2));
+        });
+        scope.validate_string("Roles must be a pair", |s| {
+            s.must(vec!["admin", "viewer"], HaveSize::with_exact_size(2));
+        });
+
+        let roles = scope.block_result("Roles must be a pair").unwrap();
+        expect!(scope.block_result("Username must be a pair").unwrap().is_valid()).to(be_true());
+        expect!(scope.block_result("Email must be valid").is_none()).to(be_true());
+        expect!(scope.block_results().len()).to(be_equal_to(3));
+        expect!(roles.is_valid()).to(be_false());
+        expect!(roles.message()).to(be_equal_to("Roles must be a pair"));
+        expect!(roles.results().len()).to(be_equal_to(2));
+        expect!(roles.failures().count()).to(be_equal_to(1));
+        expect!(scope.failure_count()).to(be_equal_to(1));
+    }
+
+    proptest! {
+        #[test]
+        fn should_validate_each_item_on_its_own(sizes in proptest::collection::vec(0usize..6, 0..20)) {
+            let scope = RustrictScope::new();
+
+            let outcomes: Vec<bool> = scope
+                .validate_iter(sizes.clone(), |item_scope, size| {
+                    validate_sizes(item_scope, &[size])
+                })
+                .map(|outcome| outcome.is_ok())
+                .collect();
+
+            let expected: Vec<bool> = sizes.iter().map(|size| *size == 3).collect();
+            expect!(outcomes).to(be_equal_to(expected));
+            expect!(scope.results().len()).to(be_equal_to(0));
+        }
+    }
+
+    #[test]
+    fn should_stop_pulling_items_after_enough_failures() {
+        let scope = RustrictScope::new();
+        let pulled = AtomicUsize::new(0);
+        let sizes = [3, 1, 3, 2, 1, 1].iter().inspect(|_| {
+            pulled.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let outcomes = scope
+            .validate_iter(sizes, |item_scope, size| validate_sizes(item_scope, &[*size]))
+            .stop_after(2)
+            .count();
+
+        expect!(outcomes).to(be_equal_to(4));
+        expect!(pulled.load(Ordering::SeqCst)).to(be_equal_to(4));
+    }
+
+    proptest! {
+        #[test]
+        fn should_merge_concurrent_results_in_the_order_of_the_items(
+            sizes in proptest::collection::vec(0usize..6, 0..100)
+        ) {
+            let scope = RustrictScope::new();
+
+            scope.validate_concurrently(&sizes, |item_scope, size| {
+                validate_sizes(item_scope, &[*size, 3]);
+            });
+
+            let sequential = RustrictScope::new();
+            for size in &sizes {
+                validate_sizes(&sequential, &[*size, 3]);
+            }
+            let messages = |scope: &RustrictScope| -> Vec<Option<String>> {
+                scope.results().iter().map(|r| r.failure().map(ConstraintError::message)).collect()
+            };
+            expect!(messages(&scope)).to(be_equal_to(messages(&sequential)));
+            expect!(scope.block_results().len()).to(be_equal_to(sizes.len() * 2));
+        }
+    }
+
+    /// Counts the outcomes it is notified of.
+    #[derive(Default)]
+    struct OutcomeCounter {
+        successes: AtomicUsize,
+        failures: AtomicUsize,
+        skipped: AtomicUsize,
+    }
+
+    impl ValidationObserver for OutcomeCounter {
+        fn on_success(&self, _message: &str) {
+            self.successes.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_failure(&self, _message: &str, _error: &ConstraintError) {
+            self.failures.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_skipped(&self, _message: &str, _skipped: &SkippedRule) {
+            self.skipped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn should_notify_the_observers_of_every_outcome(
+            sizes in proptest::collection::vec(0usize..6, 0..20)
+        ) {
+            let counter = Arc::new(OutcomeCounter::default());
+            let mut scope = RustrictScope::new();
+            scope.register_observer(counter.clone());
+            validate_sizes(&scope, &sizes);
+
+            let failures = counter.failures.load(Ordering::SeqCst);
+            let successes = counter.successes.load(Ordering::SeqCst);
+            expect!(failures).to(be_equal_to(scope.failure_count()));
+            expect!(successes).to(be_equal_to(sizes.len() - scope.failure_count()));
+        }
+    }
+
+    #[test]
+    fn should_notify_the_observers_of_skipped_rules() {
+        let counter = Arc::new(OutcomeCounter::default());
+        let mut scope = RustrictScope::new().with_deadline(Duration::ZERO);
+        scope.register_observer(counter.clone());
+        validate_sizes(&scope, &[3, 2]);
+
+        expect!(counter.skipped.load(Ordering::SeqCst)).to(be_equal_to(2));
+        expect!(counter.failures.load(Ordering::SeqCst)).to(be_equal_to(0));
+    }
+
+    #[cfg(feature = "tracing")]
+    mod when_tracing {
+        use super::*;
+        use std::fmt::Debug;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// A subscriber that records the names of the spans and the fields of the events.
+        #[derive(Clone, Default)]
+        struct Recorder {
+            spans: Arc<Mutex<Vec<String>>>,
+            events: Arc<Mutex<Vec<String>>>,
+        }
+
+        struct FieldsVisitor(String);
+
+        impl Visit for FieldsVisitor {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0.push_str(&format!("{}={:?};", field.name(), value));
+            }
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut spans = self.spans.lock().unwrap();
+                spans.push(span.metadata().name().to_string());
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut visitor = FieldsVisitor(String::new());
+                event.record(&mut visitor);
+                self.events.lock().unwrap().push(visitor.0);
+            }
+
+            fn enter(&self, _span: &Id) {}
+
+            fn exit(&self, _span: &Id) {}
+        }
+
+        #[test]
+        fn should_emit_a_span_per_block_and_an_event_per_failure() {
+            let recorder = Recorder::default();
+            tracing::subscriber::with_default(recorder.clone(), || {
+                validate_sizes(&RustrictScope::new(), &[3, 2]);
+            });
+
+            expect!(recorder.spans.lock().unwrap().clone())
+                .to(be_equal_to(vec!["validate_string", "validate_string"]));
+            let events = recorder.events.lock().unwrap();
+            expect!(events.len()).to(be_equal_to(1));
+            expect!(events[0].contains("message=Size must be 2;")).to(be_true());
+            expect!(events[0].contains("HaveSize")).to(be_true());
+        }
+    }
+}
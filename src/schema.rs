@@ -0,0 +1,357 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! JSON Schema generation from declared constraints.
+//!
+//! This module is available with the `schema` feature. Types describe the constraints attached to
+//! each of their fields through `DescribeConstraints`, and `to_json_schema` turns that description
+//! into a JSON Schema document, so API consumers can see the validation rules without reading Rust
+//! code:
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! # use rustrict::schema::*;
+//! struct User {
+//!     roles: Vec<String>,
+//! }
+//!
+//! impl DescribeConstraints for User {
+//!     fn describe_constraints(schema: &mut SchemaBuilder) {
+//!         schema
+//!             .field("roles", &NotBeEmpty)
+//!             .field("roles", &HaveNoDuplicates)
+//!             .field("roles", &BeSubsetOf::new(["admin", "editor", "viewer"]));
+//!     }
+//! }
+//!
+//! let schema = to_json_schema::<User>();
+//! // {"$schema": "...", "title": "User", "type": "object", "required": ["roles"],
+//! //  "properties": {"roles": {"type": "array", "minItems": 1, "uniqueItems": true,
+//! //                           "items": {"enum": ["admin", "editor", "viewer"]}}}}
+//! ```
+use crate::constraints::collections::be_empty::{BeEmpty, NotBeEmpty};
+use crate::constraints::collections::be_subset_of::{BeSubsetOf, BeSupersetOf};
+use crate::constraints::collections::contain_element::{ContainAll, ContainAny, ContainElement};
+use crate::constraints::collections::have_no_duplicates::HaveNoDuplicates;
+use crate::constraints::collections::have_size::HaveSize;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+/// The JSON Schema dialect of the generated documents.
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A constraint that can be expressed with JSON Schema keywords.
+pub trait JsonSchemaConstraint {
+    /// Returns the keywords that a field's schema must include to enforce this constraint.
+    fn schema_keywords(&self) -> Map<String, Value>;
+}
+
+/// A type whose fields have declared constraints.
+pub trait DescribeConstraints {
+    /// Registers the constraints of each field in the given builder.
+    fn describe_constraints(schema: &mut SchemaBuilder);
+}
+
+/// Collects the constraints of each field of a type.
+#[derive(Debug, Default)]
+pub struct SchemaBuilder {
+    properties: Map<String, Value>,
+}
+
+impl SchemaBuilder {
+    /// Attaches a constraint to a field.
+    ///
+    /// Calling this several times for the same field combines the keywords of every constraint.
+    ///
+    /// # Parameters:
+    /// - `name`: The name of the field.
+    /// - `constraint`: A constraint that the field must satisfy.
+    pub fn field(&mut self, name: &str, constraint: &dyn JsonSchemaConstraint) -> &mut Self {
+        let property = self
+            .properties
+            .entry(name)
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(keywords) = property {
+            merge_keywords(keywords, constraint.schema_keywords());
+        }
+        self
+    }
+
+    /// Builds the schema of an object with the registered fields, all of them required.
+    fn build(self, title: &str) -> Value {
+        let required: Vec<&String> = self.properties.keys().collect();
+        json!({
+            "$schema": SCHEMA_DIALECT,
+            "title": title,
+            "type": "object",
+            "required": required,
+            "properties": self.properties,
+        })
+    }
+}
+
+/// Generates the JSON Schema of a type from its declared constraints.
+///
+/// # Returns:
+/// A JSON Schema document describing an object with one property per constrained field.
+pub fn to_json_schema<T: DescribeConstraints>() -> Value {
+    let mut builder = SchemaBuilder::default();
+    T::describe_constraints(&mut builder);
+    builder.build(&title_of(std::any::type_name::<T>()))
+}
+
+/// Returns the name of a type without its path, keeping the names of its generic arguments,
+/// e.g. `Wrapper<User>` for `app::Wrapper<app::User>`.
+fn title_of(type_name: &str) -> String {
+    let mut title = String::new();
+    let mut segment_start = 0;
+    for (index, character) in type_name.char_indices() {
+        match character {
+            ':' => segment_start = index + 1,
+            '<' | '>' | ',' | ' ' | '[' | ']' | ';' | '(' | ')' | '&' => {
+                title.push_str(&type_name[segment_start..index]);
+                title.push(character);
+                segment_start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    title.push_str(&type_name[segment_start..]);
+    title
+}
+
+/// Adds the `source` keywords into `target`, without losing any constraint already in it.
+///
+/// - `minItems` and `maxItems` keep the tighter of both bounds.
+/// - The subschemas of `allOf` are accumulated.
+/// - Any other keyword already in `target` with a different value is moved into an `allOf`
+///   subschema, so that both values are enforced.
+fn merge_keywords(target: &mut Map<String, Value>, source: Map<String, Value>) {
+    for (keyword, value) in source {
+        let Some(existing) = target.get_mut(&keyword) else {
+            target.insert(keyword, value);
+            continue;
+        };
+        match (keyword.as_str(), existing, value) {
+            ("allOf", Value::Array(existing), Value::Array(new)) => existing.extend(new),
+            ("minItems", Value::Number(existing), Value::Number(new)) => {
+                if new.as_u64() > existing.as_u64() {
+                    *existing = new;
+                }
+            }
+            ("maxItems", Value::Number(existing), Value::Number(new)) => {
+                if new.as_u64() < existing.as_u64() {
+                    *existing = new;
+                }
+            }
+            (_, existing, value) if *existing == value => {}
+            (_, _, value) => {
+                let mut subschema = Map::new();
+                subschema.insert(keyword, value);
+                merge_keywords(target, Map::from_iter([("allOf".to_string(), json!([subschema]))]));
+            }
+        }
+    }
+}
+
+/// Returns the keywords of an array schema together with the given ones.
+fn array_keywords(keywords: Value) -> Map<String, Value> {
+    let mut result = Map::new();
+    result.insert("type".to_string(), json!("array"));
+    if let Value::Object(keywords) = keywords {
+        result.extend(keywords);
+    }
+    result
+}
+
+/// Serializes the given elements, skipping those that can't be represented in JSON.
+fn to_values<'a, T: Serialize + 'a>(elements: impl IntoIterator<Item = &'a T>) -> Vec<Value> {
+    elements
+        .into_iter()
+        .filter_map(|element| serde_json::to_value(element).ok())
+        .collect()
+}
+
+impl JsonSchemaConstraint for HaveSize {
+    /// Exact sizes are expressed as `minItems`/`maxItems`; sizes checked by an arbitrary predicate
+    /// can only be described as arrays.
+    fn schema_keywords(&self) -> Map<String, Value> {
+        match self.expected_size {
+            Some(size) => array_keywords(json!({ "minItems": size, "maxItems": size })),
+            None => array_keywords(json!({})),
+        }
+    }
+}
+
+impl JsonSchemaConstraint for BeEmpty {
+    fn schema_keywords(&self) -> Map<String, Value> {
+        array_keywords(json!({ "maxItems": 0 }))
+    }
+}
+
+impl JsonSchemaConstraint for NotBeEmpty {
+    fn schema_keywords(&self) -> Map<String, Value> {
+        array_keywords(json!({ "minItems": 1 }))
+    }
+}
+
+impl JsonSchemaConstraint for HaveNoDuplicates {
+    fn schema_keywords(&self) -> Map<String, Value> {
+        array_keywords(json!({ "uniqueItems": true }))
+    }
+}
+
+impl<T: Serialize> JsonSchemaConstraint for ContainElement<T> {
+    fn schema_keywords(&self) -> Map<String, Value> {
+        let element = serde_json::to_value(&self.0).unwrap_or(Value::Null);
+        array_keywords(json!({ "contains": { "const": element } }))
+    }
+}
+
+impl<T: Serialize> JsonSchemaConstraint for ContainAll<T> {
+    fn schema_keywords(&self) -> Map<String, Value> {
+        let all_of: Vec<Value> = to_values(&self.0)
+            .into_iter()
+            .map(|element| json!({ "contains": { "const": element } }))
+            .collect();
+        array_keywords(json!({ "allOf": all_of }))
+    }
+}
+
+impl<T: Serialize> JsonSchemaConstraint for ContainAny<T> {
+    fn schema_keywords(&self) -> Map<String, Value> {
+        array_keywords(json!({ "contains": { "enum": to_values(&self.0) } }))
+    }
+}
+
+impl<T: Serialize> JsonSchemaConstraint for BeSubsetOf<T> {
+    fn schema_keywords(&self) -> Map<String, Value> {
+        array_keywords(json!({ "items": { "enum": to_values(&self.reference) } }))
+    }
+}
+
+impl<T: Serialize> JsonSchemaConstraint for BeSupersetOf<T> {
+    fn schema_keywords(&self) -> Map<String, Value> {
+        let all_of: Vec<Value> = to_values(&self.reference)
+            .into_iter()
+            .map(|element| json!({ "contains": { "const": element } }))
+            .collect();
+        array_keywords(json!({ "allOf": all_of }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+
+    struct Team {
+        _members: Vec<String>,
+        _roles: Vec<String>,
+    }
+
+    impl DescribeConstraints for Team {
+        fn describe_constraints(schema: &mut SchemaBuilder) {
+            schema
+                .field("members", &NotBeEmpty)
+                .field("members", &HaveNoDuplicates)
+                .field("roles", &BeSubsetOf::new(["admin", "viewer"]))
+                .field("roles", &ContainElement("viewer"))
+                .field("roles", &BeSupersetOf::new(["viewer"]))
+                .field("roles", &ContainAll(vec!["admin"]));
+        }
+    }
+
+    #[test]
+    fn should_describe_every_constrained_field() {
+        let schema = to_json_schema::<Team>();
+
+        expect!(schema).to(be_equal_to(json!({
+            "$schema": SCHEMA_DIALECT,
+            "title": "Team",
+            "type": "object",
+            "required": ["members", "roles"],
+            "properties": {
+                "members": { "type": "array", "minItems": 1, "uniqueItems": true },
+                "roles": {
+                    "type": "array",
+                    "items": { "enum": ["admin", "viewer"] },
+                    "contains": { "const": "viewer" },
+                    "allOf": [
+                        { "contains": { "const": "viewer" } },
+                        { "contains": { "const": "admin" } }
+                    ]
+                }
+            }
+        })));
+    }
+
+    struct Playlist {
+        _songs: Vec<String>,
+    }
+
+    impl DescribeConstraints for Playlist {
+        fn describe_constraints(schema: &mut SchemaBuilder) {
+            schema
+                .field("songs", &HaveSize::with_exact_size(5))
+                .field("songs", &NotBeEmpty)
+                .field("songs", &ContainElement("intro"))
+                .field("songs", &ContainElement("outro"));
+        }
+    }
+
+    #[test]
+    fn should_keep_every_constraint_of_a_field() {
+        let schema = to_json_schema::<Playlist>();
+
+        expect!(schema["properties"]["songs"].clone()).to(be_equal_to(json!({
+            "type": "array",
+            "minItems": 5,
+            "maxItems": 5,
+            "contains": { "const": "intro" },
+            "allOf": [{ "contains": { "const": "outro" } }]
+        })));
+    }
+
+    #[test]
+    fn should_keep_the_tighter_size_bounds() {
+        let mut keywords = NotBeEmpty.schema_keywords();
+
+        merge_keywords(&mut keywords, BeEmpty.schema_keywords());
+        merge_keywords(&mut keywords, HaveSize::with_exact_size(3).schema_keywords());
+
+        expect!(Value::Object(keywords))
+            .to(be_equal_to(json!({ "type": "array", "minItems": 3, "maxItems": 0 })));
+    }
+
+    struct Wrapper<T>(T);
+
+    impl<T> DescribeConstraints for Wrapper<T> {
+        fn describe_constraints(_schema: &mut SchemaBuilder) {}
+    }
+
+    #[test]
+    fn should_title_generic_types_without_paths() {
+        let schema = to_json_schema::<Wrapper<Vec<Team>>>();
+
+        expect!(schema["title"].clone()).to(be_equal_to(json!("Wrapper<Vec<Team>>")));
+        expect!(title_of("app::Pair<app::User, &str>")).to(be_equal_to("Pair<User, &str>"));
+    }
+
+    #[test]
+    fn should_describe_sizes() {
+        expect!(Value::Object(HaveSize::with_exact_size(2).schema_keywords()))
+            .to(be_equal_to(json!({ "type": "array", "minItems": 2, "maxItems": 2 })));
+        expect!(Value::Object(HaveSize::new(|size| size > 2).schema_keywords()))
+            .to(be_equal_to(json!({ "type": "array" })));
+        expect!(Value::Object(BeEmpty.schema_keywords()))
+            .to(be_equal_to(json!({ "type": "array", "maxItems": 0 })));
+    }
+
+    #[test]
+    fn should_describe_alternatives() {
+        expect!(Value::Object(ContainAny(vec![1, 2]).schema_keywords()))
+            .to(be_equal_to(json!({ "type": "array", "contains": { "enum": [1, 2] } })));
+    }
+}
@@ -2,10 +2,19 @@
  * Copyright (c) 2024, Ignacio Slater M.
  * 2-Clause BSD License.
  */
-use crate::constraints::constraint::Constraint;
+use crate::constraints::be_equal_to::{BeEqualTo, BeOneOf};
+use crate::constraints::constraint::{AsSubject, Constraint};
+use crate::constraints::not::{not, Not};
 use crate::errors::constraint_error::ConstraintError;
-use std::fmt::{Display, Formatter};
+use crate::errors::skipped_rule::SkippedRule;
+use crate::rule_result::RuleResult;
+use crate::rustrict_scope::Contexts;
+use crate::validation_observer::ValidationObserver;
+use std::any::Any;
+use std::fmt::{Debug, Display, Formatter};
+use std::panic::Location;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 /// A scope for validating constraints on a string value.
 ///
@@ -14,18 +23,32 @@ use std::sync::{Arc, Mutex};
 ///
 /// # Fields:
 /// - `message`: The validation message or label associated with the rule.
-/// - `results`: A shared, thread-safe container for storing validation results.
+/// - `results`: A shared, thread-safe container for storing the result of every rule, including
+///   the rules skipped after the deadline.
 /// - `exception_generator`: An optional closure for generating custom exceptions.
+/// - `deadline`: An optional instant after which rules are recorded as skipped instead of run.
+/// - `observers`: The observers notified of the outcome of every rule.
+/// - `warnings`: A shared, thread-safe container for the failures of soft rules (`should`).
+/// - `outcomes`: The results of the rules of this scope alone, returned as a `BlockResult`.
+/// - `contexts`: The context objects attached to the root scope, keyed by their type.
 ///
 /// # Conceptual Differences:
 /// - **Thread Safety:** Rust's `Arc<Mutex<>>` ensures thread-safe shared ownership and mutation,
 ///     which is more explicit than Kotlin's coroutines and thread safety mechanisms.
 /// - **Lifetimes and Ownership:** Rust's strict ownership model, enforced by the borrow checker,
 ///     ensures that data races are impossible without needing a garbage collector, unlike Kotlin.
-pub(crate) struct StringScope {
+///
+/// Cloning a `StringScope` returns a handle to the same scope, sharing its results.
+#[derive(Clone)]
+pub struct StringScope {
     message: String,
-    results: Arc<Mutex<Vec<Result<(), ConstraintError>>>>,
-    exception_generator: Option<Box<dyn Fn(String) -> ConstraintError>>,
+    results: Arc<Mutex<Vec<RuleResult>>>,
+    exception_generator: Option<Arc<dyn Fn(String) -> ConstraintError>>,
+    deadline: Option<Instant>,
+    observers: Vec<Arc<dyn ValidationObserver>>,
+    warnings: Arc<Mutex<Vec<ConstraintError>>>,
+    outcomes: Arc<Mutex<Vec<RuleResult>>>,
+    contexts: Contexts,
 }
 
 impl StringScope {
@@ -42,12 +65,17 @@ impl StringScope {
     /// A `StringScope` instance.
     pub(crate) fn new(
         message: String,
-        results: Arc<Mutex<Vec<Result<(), ConstraintError>>>>,
+        results: Arc<Mutex<Vec<RuleResult>>>,
     ) -> Self {
         Self {
             message,
             results,
             exception_generator: None,
+            deadline: None,
+            observers: Vec::new(),
+            warnings: Arc::new(Mutex::new(Vec::new())),
+            outcomes: Arc::new(Mutex::new(Vec::new())),
+            contexts: Contexts::default(),
         }
     }
 
@@ -65,14 +93,183 @@ impl StringScope {
     /// A `StringScope` instance.
     pub(crate) fn new_with_exception_generator(
         message: String,
-        results: Arc<Mutex<Vec<Result<(), ConstraintError>>>>,
+        results: Arc<Mutex<Vec<RuleResult>>>,
         exception_generator: Box<dyn Fn(String) -> ConstraintError>,
     ) -> Self {
         Self {
             message,
             results,
-            exception_generator: Some(exception_generator),
+            exception_generator: Some(Arc::from(exception_generator)),
+            deadline: None,
+            observers: Vec::new(),
+            warnings: Arc::new(Mutex::new(Vec::new())),
+            outcomes: Arc::new(Mutex::new(Vec::new())),
+            contexts: Contexts::default(),
+        }
+    }
+
+    /// Sets the instant after which the rules of this scope are skipped.
+    ///
+    /// Once the deadline has passed, every new rule records a `SkippedRule` without evaluating its
+    /// constraint or predicate.
+    ///
+    /// # Parameters:
+    /// - `deadline`: The deadline, or `None` to run every rule.
+    ///
+    /// # Returns:
+    /// The same `StringScope`, with its deadline set.
+    pub(crate) fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Sets the observers notified of the outcome of every rule of this scope.
+    ///
+    /// # Parameters:
+    /// - `observers`: The observers to notify.
+    ///
+    /// # Returns:
+    /// The same `StringScope`, with its observers set.
+    pub(crate) fn with_observers(mut self, observers: Vec<Arc<dyn ValidationObserver>>) -> Self {
+        self.observers = observers;
+        self
+    }
+
+    /// Sets the container where the failures of the soft rules of this scope are recorded.
+    ///
+    /// # Parameters:
+    /// - `warnings`: A shared, thread-safe container for warnings.
+    ///
+    /// # Returns:
+    /// The same `StringScope`, with its warnings' container set.
+    pub(crate) fn with_warnings(mut self, warnings: Arc<Mutex<Vec<ConstraintError>>>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    /// Sets the context objects available to the rules of this scope.
+    ///
+    /// # Parameters:
+    /// - `contexts`: The context objects, keyed by their type.
+    ///
+    /// # Returns:
+    /// The same `StringScope`, with its contexts set.
+    pub(crate) fn with_contexts(mut self, contexts: Contexts) -> Self {
+        self.contexts = contexts;
+        self
+    }
+
+    /// Returns the context object of the given type attached to the root scope, if any.
+    ///
+    /// This lets rules declared far from the call site, like in a `Validate` implementation, read
+    /// request-wide data such as the current locale or the limits of a tenant.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// struct Limits { max_roles: usize }
+    ///
+    /// let scope = RustrictScope::new().with_context(Limits { max_roles: 1 });
+    /// scope.validate_string("Too many roles", |s| {
+    ///     let max = s.context::<Limits>().map_or(0, |limits| limits.max_roles);
+    ///     s.must(vec!["admin"], HaveSize::new(move |size| size <= max));
+    /// });
+    /// assert!(scope.is_valid());
+    /// ```
+    pub fn context<C: Any>(&self) -> Option<&C> {
+        self.contexts.get(&std::any::TypeId::of::<C>())?.downcast_ref()
+    }
+
+    /// Consumes the scope, returning the results of its rules alone.
+    pub(crate) fn into_block_result(self) -> BlockResult {
+        BlockResult {
+            message: self.message,
+            results: std::mem::take(&mut *self.outcomes.lock().unwrap()),
+        }
+    }
+
+    /// Notifies the observers of the outcome of a rule and adds it to the results.
+    pub(crate) fn record(&self, result: RuleResult) {
+        for observer in &self.observers {
+            match &result {
+                RuleResult::Passed => observer.on_success(&self.message),
+                RuleResult::Failed(error) => observer.on_failure(&self.message, error),
+                RuleResult::Skipped(skipped) => observer.on_skipped(&self.message, skipped),
+            }
         }
+        self.outcomes.lock().unwrap().push(result.clone());
+        self.results.lock().unwrap().push(result);
+    }
+
+    /// Runs a rule whose outcome is computed by `evaluate`, unless the deadline has passed.
+    ///
+    /// This lets the scopes built on top of this one, like `JsonScope`, check values that are only
+    /// available once the rule runs, while keeping the deadline and the observers of the root
+    /// scope.
+    ///
+    /// # Parameters:
+    /// - `location`: The location of the rule in the caller's code.
+    /// - `evaluate`: A closure returning the outcome of the rule.
+    ///
+    /// # Returns:
+    /// The outcome of the rule.
+    pub(crate) fn check(
+        &self,
+        location: &'static Location<'static>,
+        evaluate: impl FnOnce() -> Result<(), ConstraintError>,
+    ) -> RuleOutcome {
+        if self.skip_if_expired(location) {
+            return RuleOutcome::new(self, false);
+        }
+        let result = evaluate();
+        let passed = result.is_ok();
+        self.record(result.into());
+        RuleOutcome::new(self, passed)
+    }
+
+    /// Runs a soft rule whose outcome is computed by `evaluate`, recording its failure as a
+    /// warning.
+    ///
+    /// This is the counterpart of `check` for `should` rules; like them, a rule skipped after the
+    /// deadline is recorded as a warning.
+    ///
+    /// # Parameters:
+    /// - `location`: The location of the rule in the caller's code.
+    /// - `evaluate`: A closure returning the outcome of the rule.
+    pub(crate) fn check_softly(
+        &self,
+        location: &'static Location<'static>,
+        evaluate: impl FnOnce() -> Result<(), ConstraintError>,
+    ) {
+        let outcome = match self.expired(location) {
+            Some(skipped) => {
+                let message = skipped.to_string();
+                Err(ConstraintError::new(move || message.clone()).with_location(location))
+            }
+            None => evaluate(),
+        };
+        if let Err(warning) = outcome {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    /// Returns the `SkippedRule` of the rule at the given location if the deadline has passed.
+    fn expired(&self, location: &'static Location<'static>) -> Option<SkippedRule> {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+            .then(|| SkippedRule::new(self.message.clone(), location))
+    }
+
+    /// Records the rule at the given location as skipped if the deadline has passed.
+    ///
+    /// # Returns:
+    /// `true` if the rule was skipped and must not be evaluated.
+    fn skip_if_expired(&self, location: &'static Location<'static>) -> bool {
+        let Some(skipped) = self.expired(location) else {
+            return false;
+        };
+        self.record(RuleResult::Skipped(skipped));
+        true
     }
 
     /// Validates that the given value satisfies or does not satisfy the specified constraint.
@@ -84,22 +281,91 @@ impl StringScope {
     /// - `value`: The value to validate.
     /// - `constraint`: The constraint to check against the value.
     /// - `condition`: A boolean indicating whether the constraint should be satisfied (`true`) or not (`false`).
-    fn validate<T, C>(&self, value: T, constraint: C, condition: bool)
+    /// - `message`: A closure returning the message of the rule's error, called only if it fails.
+    /// - `exception`: An exception generator for this rule alone, which takes precedence over the
+    ///   scope's one.
+    ///
+    /// With the `tracing` feature enabled, every failure emits a `WARN` event with the scope's
+    /// message and the type name of the constraint.
+    ///
+    /// # Returns:
+    /// The outcome of the rule.
+    #[track_caller]
+    fn validate<V, T, C>(
+        &self,
+        value: V,
+        constraint: C,
+        condition: bool,
+        message: impl FnOnce() -> String,
+        exception: Option<&dyn Fn(String) -> ConstraintError>,
+    ) -> RuleOutcome
     where
+        T: ?Sized,
+        V: AsSubject<T>,
         C: Constraint<T>,
     {
-        let exception = || {
-            self.exception_generator
-                .as_ref()
-                .map(|gen| gen(self.message.clone()))
-                .unwrap_or_else(|| constraint.generate_exception(self.message.clone()))
+        let location = Location::caller();
+        self.check(location, || {
+            self.evaluate(value, constraint, condition, message, exception, location)
+        })
+    }
+
+    /// Checks the value against the constraint, creating the error of a failed rule at the given
+    /// location.
+    ///
+    /// # Returns:
+    /// `Ok(())` if the constraint's outcome matches `condition`, or the rule's error otherwise.
+    fn evaluate<V, T, C>(
+        &self,
+        value: V,
+        constraint: C,
+        condition: bool,
+        message: impl FnOnce() -> String,
+        exception: Option<&dyn Fn(String) -> ConstraintError>,
+        location: &'static Location<'static>,
+    ) -> Result<(), ConstraintError>
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+    {
+        if constraint.validate(value.as_subject()) == condition {
+            return Ok(());
+        }
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            message = %self.message,
+            constraint = std::any::type_name::<C>(),
+            "constraint failed"
+        );
+        let message = message();
+        let error = match exception.or(self.exception_generator.as_deref()) {
+            Some(gen) => gen(message),
+            None if condition => {
+                constraint.generate_exception_with_value(value.as_subject(), message)
+            }
+            None => Not(constraint).generate_exception_with_value(value.as_subject(), message),
         };
+        Err(error
+            .with_location(location)
+            .with_rule(std::any::type_name::<C>()))
+    }
 
-        let mut results = self.results.lock().unwrap();
-        results.push(if constraint.validate(&value) == condition {
-            Ok(())
-        } else {
-            Err(exception())
+    /// Checks a soft rule, recording its failure as a warning instead of an error.
+    ///
+    /// Observers are not notified of soft rules, since they don't affect the validity of the scope.
+    /// For the same reason, a soft rule skipped after the deadline is recorded as a warning instead
+    /// of a `SkippedRule`.
+    #[track_caller]
+    fn warn<V, T, C>(&self, value: V, constraint: C, condition: bool)
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+    {
+        let location = Location::caller();
+        self.check_softly(location, || {
+            self.evaluate(value, constraint, condition, || self.message.clone(), None, location)
         });
     }
 
@@ -109,13 +375,114 @@ impl StringScope {
     /// successful validation or pushes a custom or default `ConstraintError` into the results.
     ///
     /// # Parameters:
+    /// - `value`: The value to validate. It can be owned or borrowed: a constraint on `[T]` accepts
+    ///   `Vec<T>`, `&Vec<T>` and `&[T]`, and one on `str` accepts `String`, `&String` and `&str`.
+    /// - `constraint`: The constraint that the value must satisfy.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let scope = RustrictScope::new();
+    /// let roles = vec!["admin".to_string()];
+    /// let name = "Ana".to_string();
+    /// scope.validate_string("User", |s| {
+    ///     s.must(&roles, NotBeEmpty);
+    ///     s.must(&name, |name: &str| !name.is_empty());
+    /// });
+    /// assert!(scope.is_valid());
+    /// ```
+    ///
+    /// # Returns:
+    /// The outcome of the rule, to chain rules that only make sense if it passed through `then`.
+    #[track_caller]
+    pub fn must<V, T, C>(&self, value: V, constraint: C) -> RuleOutcome
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+    {
+        self.validate(value, constraint, true, || self.message.clone(), None)
+    }
+
+    /// Validates that the given value satisfies the specified constraint, overriding the scope's
+    /// message for this rule alone.
+    ///
+    /// This is useful when a block checks several properties and a single message would be too
+    /// coarse to tell them apart.
+    ///
+    /// # Parameters:
     /// - `value`: The value to validate.
     /// - `constraint`: The constraint that the value must satisfy.
-    fn must<T, C>(&self, value: T, constraint: C)
+    /// - `message`: A closure returning the rule's message, called only if the rule fails.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let scope = RustrictScope::new();
+    /// # let roles = vec!["admin", "admin"];
+    /// scope.validate_string("Roles must be valid", |s| {
+    ///     s.must(roles.clone(), NotBeEmpty);
+    ///     s.must_with_message(roles.clone(), HaveNoDuplicates, || "Roles must be unique".into());
+    /// });
+    /// assert!(scope.failures()[0].message().starts_with("Roles must be unique"));
+    /// ```
+    #[track_caller]
+    pub fn must_with_message<V, T, C, M>(&self, value: V, constraint: C, message: M) -> RuleOutcome
     where
+        T: ?Sized,
+        V: AsSubject<T>,
         C: Constraint<T>,
+        M: FnOnce() -> String,
     {
-        self.validate(value, constraint, true);
+        self.validate(value, constraint, true, message, None)
+    }
+
+    /// Validates that the given value satisfies a predicate that also reads a context object.
+    ///
+    /// The context is the object of type `Ctx` attached to the root scope through
+    /// `RustrictScope::with_context`, so the predicate doesn't need to capture it. If no such
+    /// context was attached, the rule fails with a message naming the missing type.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `predicate`: A closure that checks the value against the context.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// struct Tenant { allowed_roles: Vec<&'static str> }
+    ///
+    /// fn roles_rule(s: &StringScope, role: &'static str) {
+    ///     s.must_in_context(role, |role: &&str, tenant: &Tenant| {
+    ///         tenant.allowed_roles.contains(role)
+    ///     });
+    /// }
+    ///
+    /// let scope = RustrictScope::new().with_context(Tenant { allowed_roles: vec!["admin"] });
+    /// scope.validate_string("Role must be allowed", |s| roles_rule(s, "admin"));
+    /// assert!(scope.is_valid());
+    /// ```
+    #[track_caller]
+    pub fn must_in_context<Ctx, V, T, P>(&self, value: V, predicate: P) -> RuleOutcome
+    where
+        Ctx: Any,
+        T: ?Sized,
+        V: AsSubject<T>,
+        P: Fn(&T, &Ctx) -> bool,
+    {
+        match self.context::<Ctx>() {
+            Some(context) => self.must(value, |value: &T| predicate(value, context)),
+            None => self.validate(
+                value,
+                |_: &T| false,
+                true,
+                || {
+                    let context = std::any::type_name::<Ctx>();
+                    format!("{}: missing context {}", self.message, context)
+                },
+                None,
+            ),
+        }
     }
 
     /// Validates that the given value does not satisfy the specified constraint.
@@ -126,11 +493,211 @@ impl StringScope {
     /// # Parameters:
     /// - `value`: The value to validate.
     /// - `constraint`: The constraint that the value must not satisfy.
-    fn must_not<T, C>(&self, value: T, constraint: C)
+    ///
+    /// # Returns:
+    /// The outcome of the rule, to chain rules that only make sense if it passed through `then`.
+    #[track_caller]
+    pub fn must_not<V, T, C>(&self, value: V, constraint: C) -> RuleOutcome
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+    {
+        self.validate(value, constraint, false, || self.message.clone(), None)
+    }
+
+    /// Validates that the given value does not satisfy the specified constraint, overriding the
+    /// scope's message for this rule alone.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `constraint`: The constraint that the value must not satisfy.
+    /// - `message`: A closure returning the rule's message, called only if the rule fails.
+    #[track_caller]
+    pub fn must_not_with_message<V, T, C, M>(
+        &self,
+        value: V,
+        constraint: C,
+        message: M,
+    ) -> RuleOutcome
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+        M: FnOnce() -> String,
+    {
+        self.validate(value, constraint, false, message, None)
+    }
+
+    /// Validates that the given value satisfies the specified constraint, creating its error with
+    /// the given exception generator.
+    ///
+    /// The generator takes precedence over the constraint's own errors and over the generator of
+    /// the scope, so different rules of a block can produce different errors.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `constraint`: The constraint that the value must satisfy.
+    /// - `exception`: A closure that creates the error of the rule from the scope's message.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let scope = RustrictScope::new();
+    /// scope.validate_string("Roles", |s| {
+    ///     s.must_with_exception(vec!["admin"; 2], HaveNoDuplicates, |message| {
+    ///         ConstraintError::new(move || format!("{} are repeated", message))
+    ///     });
+    /// });
+    /// assert_eq!(scope.failures()[0].message(), "Roles are repeated");
+    /// ```
+    #[track_caller]
+    pub fn must_with_exception<V, T, C, G>(
+        &self,
+        value: V,
+        constraint: C,
+        exception: G,
+    ) -> RuleOutcome
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+        G: Fn(String) -> ConstraintError,
+    {
+        self.validate(value, constraint, true, || self.message.clone(), Some(&exception))
+    }
+
+    /// Validates that the given value does not satisfy the specified constraint, creating its error
+    /// with the given exception generator.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `constraint`: The constraint that the value must not satisfy.
+    /// - `exception`: A closure that creates the error of the rule from the scope's message.
+    #[track_caller]
+    pub fn must_not_with_exception<V, T, C, G>(
+        &self,
+        value: V,
+        constraint: C,
+        exception: G,
+    ) -> RuleOutcome
     where
+        T: ?Sized,
+        V: AsSubject<T>,
         C: Constraint<T>,
+        G: Fn(String) -> ConstraintError,
     {
-        self.validate(value, constraint, false);
+        self.validate(value, constraint, false, || self.message.clone(), Some(&exception))
+    }
+
+    /// Validates that the given value is equal to the expected one.
+    ///
+    /// The error of a failed rule includes both values.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `expected`: The value it must be equal to.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let scope = RustrictScope::new();
+    /// scope.validate_string("Role", |s| s.must_be_equal_to("guest", "admin"));
+    /// assert_eq!(scope.failures()[0].message(), r#"Role: expected "admin", got "guest""#);
+    /// ```
+    #[track_caller]
+    pub fn must_be_equal_to<T>(&self, value: T, expected: T) -> RuleOutcome
+    where
+        T: PartialEq + Debug,
+    {
+        self.must(value, BeEqualTo(expected))
+    }
+
+    /// Validates that the given value is not equal to the rejected one.
+    ///
+    /// The error of a failed rule includes both values, e.g. `expected NOT "root", got "root"`.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `rejected`: The value it must differ from.
+    #[track_caller]
+    pub fn must_not_be_equal_to<T>(&self, value: T, rejected: T) -> RuleOutcome
+    where
+        T: PartialEq + Debug,
+    {
+        self.must(value, not(BeEqualTo(rejected)))
+    }
+
+    /// Validates that the given value is one of the allowed ones.
+    ///
+    /// The error of a failed rule includes the allowed values and the rejected one.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `values`: The allowed values.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let scope = RustrictScope::new();
+    /// scope.validate_string("Role", |s| s.must_be_one_of("guest", ["admin", "viewer"]));
+    /// assert_eq!(
+    ///     scope.failures()[0].message(),
+    ///     r#"Role: expected one of ["admin", "viewer"], got "guest""#
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn must_be_one_of<T>(&self, value: T, values: impl IntoIterator<Item = T>) -> RuleOutcome
+    where
+        T: PartialEq + Debug,
+    {
+        self.must(value, BeOneOf(values.into_iter().collect()))
+    }
+
+    /// Checks that the given value satisfies the specified constraint, as a soft rule.
+    ///
+    /// Unlike `must`, a failure is recorded as a warning: it doesn't make the scope invalid, but it
+    /// can be reported through `RustrictScope::warnings`. This is useful for data-quality rules
+    /// that flag suspicious values without rejecting them, like a `should` in Kotest.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `constraint`: The constraint that the value should satisfy.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// # let scope = RustrictScope::new();
+    /// # let tags: Vec<String> = vec![];
+    /// scope.validate_string("Tags should not be empty", |s| s.should(tags, NotBeEmpty));
+    /// assert!(scope.is_valid());
+    /// assert_eq!(scope.warning_count(), 1);
+    /// ```
+    #[track_caller]
+    pub fn should<V, T, C>(&self, value: V, constraint: C)
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+    {
+        self.warn(value, constraint, true);
+    }
+
+    /// Checks that the given value does not satisfy the specified constraint, as a soft rule.
+    ///
+    /// This is the inverse of `should`; its failures are recorded as warnings as well.
+    ///
+    /// # Parameters:
+    /// - `value`: The value to validate.
+    /// - `constraint`: The constraint that the value should not satisfy.
+    #[track_caller]
+    pub fn should_not<V, T, C>(&self, value: V, constraint: C)
+    where
+        T: ?Sized,
+        V: AsSubject<T>,
+        C: Constraint<T>,
+    {
+        self.warn(value, constraint, false);
     }
 
     /// Defines a custom constraint based on a predicate.
@@ -140,15 +707,146 @@ impl StringScope {
     ///
     /// # Parameters:
     /// - `predicate`: A closure that returns `true` if the constraint is satisfied.
-    fn constraint(&self, predicate: impl Fn() -> bool) {
-        let message = self.message.clone(); // Clone the message to have an owned value with 'static lifetime
+    ///
+    /// # Returns:
+    /// The outcome of the rule, to chain rules that only make sense if it passed through `then`.
+    #[track_caller]
+    pub fn constraint(&self, predicate: impl Fn() -> bool) -> RuleOutcome {
+        self.constraint_with_message(predicate, || self.message.clone())
+    }
 
-        let mut results = self.results.lock().unwrap();
-        results.push(if predicate() {
-            Ok(())
+    /// Defines a custom constraint based on a predicate, overriding the scope's message for this
+    /// rule alone.
+    ///
+    /// # Parameters:
+    /// - `predicate`: A closure that returns `true` if the constraint is satisfied.
+    /// - `message`: A closure returning the rule's message, called only if the rule fails.
+    #[track_caller]
+    pub fn constraint_with_message(
+        &self,
+        predicate: impl Fn() -> bool,
+        message: impl FnOnce() -> String,
+    ) -> RuleOutcome {
+        if self.skip_if_expired(Location::caller()) {
+            return RuleOutcome::new(self, false);
+        }
+        let location = Location::caller();
+        let passed = predicate();
+
+        self.record(if passed {
+            RuleResult::Passed
         } else {
-            Err(ConstraintError::new(move || message.clone())) // Use the cloned message
+            #[cfg(feature = "tracing")]
+            tracing::warn!(message = %self.message, constraint = "predicate", "constraint failed");
+            let message = message(); // An owned value with 'static lifetime
+            let error = ConstraintError::new(move || message.clone()).with_location(location);
+            RuleResult::Failed(error)
         });
+        RuleOutcome::new(self, passed)
+    }
+}
+
+/// The outcome of a rule declared in a `StringScope`.
+///
+/// It guards dependent rules: the rules passed to `then` only run if this one passed, so a blank
+/// value doesn't produce a cascade of redundant failures from rules that assume it isn't blank.
+/// Rules that don't run are not recorded at all, neither as passed nor as failed.
+///
+/// A passed outcome keeps a handle to the scope that shares its results, so it can be returned
+/// from a validation block.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # let scope = RustrictScope::new();
+/// # let roles: Vec<&str> = vec![];
+/// scope.validate_string("Roles must be valid", |s| {
+///     s.must(roles.clone(), NotBeEmpty)
+///         .then(|s| s.must(roles.clone(), HaveNoDuplicates))
+///         .then(|s| s.must(roles.clone(), BeSubsetOf::new(["admin", "viewer"])));
+/// });
+/// assert_eq!(scope.failure_count(), 1);
+/// ```
+pub struct RuleOutcome {
+    scope: Option<StringScope>,
+}
+
+impl RuleOutcome {
+    fn new(scope: &StringScope, passed: bool) -> Self {
+        Self {
+            scope: passed.then(|| scope.clone()),
+        }
+    }
+
+    /// Returns `true` if the rule passed.
+    pub fn passed(&self) -> bool {
+        self.scope.is_some()
+    }
+
+    /// Runs dependent rules only if this rule passed.
+    ///
+    /// # Parameters:
+    /// - `rules`: A closure that declares the dependent rules through the same `StringScope`.
+    ///
+    /// # Returns:
+    /// An outcome that passed if this rule and every dependent rule passed, to keep chaining.
+    pub fn then<F, R>(self, rules: F) -> RuleOutcome
+    where
+        F: FnOnce(&StringScope) -> R,
+    {
+        let Some(scope) = self.scope else {
+            return self;
+        };
+        let recorded = scope.outcomes.lock().unwrap().len();
+        rules(&scope);
+        let passed = scope.outcomes.lock().unwrap()[recorded..]
+            .iter()
+            .all(RuleResult::is_passed);
+        RuleOutcome::new(&scope, passed)
+    }
+}
+
+/// The outcome of the rules of a single validation block.
+///
+/// Obtained through `RustrictScope::block_result`, so a block can be checked on its own regardless
+/// of the rest of the validation, e.g. to highlight the fields of a form that failed. It is a
+/// snapshot: resetting or rolling back the root scope doesn't change it.
+#[derive(Clone, Debug)]
+pub struct BlockResult {
+    message: String,
+    results: Vec<RuleResult>,
+}
+
+impl BlockResult {
+    /// Returns the message of the block.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the results of the block's rules, in the order they were recorded.
+    pub fn results(&self) -> &[RuleResult] {
+        &self.results
+    }
+
+    /// Returns an iterator over the errors of the block's failed rules.
+    pub fn failures(&self) -> impl Iterator<Item = &ConstraintError> {
+        self.results.iter().filter_map(RuleResult::failure)
+    }
+
+    /// Returns an iterator over the rules of the block that were skipped after the deadline.
+    pub fn skipped(&self) -> impl Iterator<Item = &SkippedRule> {
+        self.results.iter().filter_map(RuleResult::skipped)
+    }
+
+    /// Returns `true` if no rule of the block failed or was skipped.
+    pub fn is_valid(&self) -> bool {
+        self.results.iter().all(RuleResult::is_passed)
+    }
+
+    /// Appends the results of another block with the same message.
+    pub(crate) fn merge(mut self, other: BlockResult) -> Self {
+        self.results.extend(other.results);
+        self
     }
 }
 
@@ -182,6 +880,339 @@ mod tests {
     mod when_validating_a_must_clause {
         use super::*;
 
+        proptest! {
+            #[test]
+            fn should_record_a_success_if_the_constraint_holds(
+                constraint in collection_have_size(0usize..10)
+            ) {
+                let scope = create_string_scope("Size".to_string());
+
+                scope.must(vec![0; constraint.expected_size.unwrap()], constraint);
+
+                let results = scope.results.lock().unwrap();
+                expect!(results.len()).to(be_equal_to(1));
+                expect!(results[0].is_passed()).to(be_true());
+            }
+
+            #[test]
+            fn should_record_a_failure_if_the_constraint_does_not_hold(
+                constraint in collection_have_size(1usize..10)
+            ) {
+                let scope = create_string_scope("Size".to_string());
+
+                scope.must(Vec::<u8>::new(), constraint);
+
+                let results = scope.results.lock().unwrap();
+                expect!(results.len()).to(be_equal_to(1));
+                expect!(results[0].is_failed()).to(be_true());
+            }
+
+            #[test]
+            fn should_invert_the_constraint_of_a_must_not_clause(
+                constraint in collection_have_size(0usize..10)
+            ) {
+                let scope = create_string_scope("Size".to_string());
+
+                scope.must_not(vec![0; constraint.expected_size.unwrap()], constraint);
+
+                expect!(scope.results.lock().unwrap()[0].is_failed()).to(be_true());
+            }
+        }
+
+        #[test]
+        fn should_describe_the_negated_expectation_of_a_must_not_clause() {
+            let scope = create_string_scope("Size".to_string());
+
+            scope.must_not(vec![1, 2, 3], HaveSize::with_exact_size(3));
+
+            let results = scope.results.lock().unwrap();
+            let error = results[0].failure().unwrap();
+            expect!(error.message()).to(be_equal_to("Size: expected NOT size 3, got 3"));
+            expect!(error.hint()).to(be_none());
+        }
+    }
+
+    mod when_recording_a_failure {
+        use super::*;
+
+        /// Returns the location of the only error recorded in the scope's results.
+        fn failure_location(scope: &StringScope) -> &'static Location<'static> {
+            let results = scope.results.lock().unwrap();
+            results[0].failure().unwrap().location().unwrap()
+        }
+
+        #[test]
+        fn should_record_the_location_of_a_must_clause() {
+            let scope = create_string_scope("Size".to_string());
+            let line = line!() + 1;
+            scope.must(vec![1, 2, 3], HaveSize::with_exact_size(2));
+
+            let location = failure_location(&scope);
+            expect!(location.file()).to(be_equal_to(file!()));
+            expect!(location.line()).to(be_equal_to(line));
+        }
+
+        #[test]
+        fn should_record_the_location_of_a_must_not_clause() {
+            let scope = create_string_scope("Size".to_string());
+            let line = line!() + 1;
+            scope.must_not(vec![1, 2, 3], HaveSize::with_exact_size(3));
+
+            expect!(failure_location(&scope).line()).to(be_equal_to(line));
+        }
+
+        #[test]
+        fn should_record_the_location_of_a_custom_constraint() {
+            let scope = create_string_scope("Always fails".to_string());
+            let line = line!() + 1;
+            scope.constraint(|| false);
+
+            expect!(failure_location(&scope).line()).to(be_equal_to(line));
+        }
+    }
+
+    mod when_the_deadline_has_passed {
+        use super::*;
+
+        #[test]
+        fn should_skip_every_rule() {
+            let scope = create_string_scope("Size".to_string()).with_deadline(Some(Instant::now()));
+            let evaluated = std::cell::Cell::new(false);
+
+            scope.must(vec![1, 2, 3], HaveSize::with_exact_size(3));
+            scope.constraint(|| {
+                evaluated.set(true);
+                true
+            });
+
+            let results = scope.results.lock().unwrap();
+            expect!(results.len()).to(be_equal_to(2));
+            expect!(results.iter().all(RuleResult::is_skipped)).to(be_true());
+            expect!(evaluated.get()).to(be_false());
+        }
+
+        #[test]
+        fn should_run_rules_before_the_deadline() {
+            let deadline = Instant::now() + std::time::Duration::from_secs(3600);
+            let scope = create_string_scope("Size".to_string()).with_deadline(Some(deadline));
+
+            scope.must(vec![1, 2, 3], HaveSize::with_exact_size(3));
+
+            expect!(scope.results.lock().unwrap()[0].is_passed()).to(be_true());
+        }
+    }
+
+    mod when_validating_borrowed_values {
+        use super::*;
+        use crate::constraints::collections::be_empty::NotBeEmpty;
+
+        fn is_capitalized(value: &str) -> bool {
+            value.starts_with(char::is_uppercase)
+        }
+
+        proptest! {
+            #[test]
+            fn should_accept_owned_and_borrowed_slices(collection: Vec<u8>) {
+                let scope = create_string_scope("Collection".to_string());
+                let expected = !collection.is_empty();
+
+                expect!(scope.must(&collection, NotBeEmpty).passed()).to(be_equal_to(expected));
+                expect!(scope.must(&collection[..], NotBeEmpty).passed()).to(be_equal_to(expected));
+                expect!(scope.must(collection, NotBeEmpty).passed()).to(be_equal_to(expected));
+            }
+
+            #[test]
+            fn should_accept_owned_and_borrowed_strings(value in "[a-zA-Z]{0,8}") {
+                let scope = create_string_scope("Name".to_string());
+                let expected = is_capitalized(&value);
+
+                expect!(scope.must(value.as_str(), is_capitalized).passed())
+                    .to(be_equal_to(expected));
+                expect!(scope.must(&value, is_capitalized).passed()).to(be_equal_to(expected));
+                expect!(scope.must(value, is_capitalized).passed()).to(be_equal_to(expected));
+            }
+        }
+    }
+
+    mod when_overriding_the_message_of_a_rule {
+        use super::*;
+
+        #[test]
+        fn should_use_the_custom_message_only_for_that_rule() {
+            let scope = create_string_scope("Size".to_string());
+
+            scope.must_with_message(vec![1], HaveSize::with_exact_size(2), || "Pair".into());
+            scope.must_not_with_message(vec![1], HaveSize::with_exact_size(1), || "Odd".into());
+            scope.constraint_with_message(|| false, || "Never".into());
+            scope.must(vec![1], HaveSize::with_exact_size(2));
+
+            let messages: Vec<String> = scope
+                .results
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|result| result.failure().unwrap().message())
+                .collect();
+            expect!(messages).to(be_equal_to(vec![
+                "Pair: expected size 2, got 1".to_string(),
+                "Odd: expected NOT size 1, got 1".to_string(),
+                "Never".to_string(),
+                "Size: expected size 2, got 1".to_string(),
+            ]));
+        }
+
+        #[test]
+        fn should_not_build_the_message_of_a_passing_rule() {
+            let scope = create_string_scope("Size".to_string());
+
+            scope.must_with_message(vec![1], HaveSize::with_exact_size(1), || unreachable!());
+
+            expect!(scope.results.lock().unwrap()[0].is_passed()).to(be_true());
+        }
+    }
+
+    mod when_comparing_values {
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn should_check_equality(value: u8, other: u8) {
+                let scope = create_string_scope("Value".to_string());
+
+                expect!(scope.must_be_equal_to(value, other).passed())
+                    .to(be_equal_to(value == other));
+                expect!(scope.must_not_be_equal_to(value, other).passed())
+                    .to(be_equal_to(value != other));
+            }
+
+            #[test]
+            fn should_check_membership(value: u8, values: Vec<u8>) {
+                let scope = create_string_scope("Value".to_string());
+
+                let outcome = scope.must_be_one_of(value, values.clone());
+
+                expect!(outcome.passed()).to(be_equal_to(values.contains(&value)));
+            }
+        }
+
+        #[test]
+        fn should_describe_both_operands_of_an_inequality() {
+            let scope = create_string_scope("User".to_string());
+
+            scope.must_not_be_equal_to("root", "root");
+
+            let results = scope.results.lock().unwrap();
+            expect!(results[0].failure().unwrap().message())
+                .to(be_equal_to(r#"User: expected NOT "root", got "root""#));
+        }
+    }
+
+    mod when_overriding_the_exception_of_a_rule {
+        use super::*;
+        use crate::constraints::with_exception::with_exception;
+
+        fn tagged(tag: &'static str) -> impl Fn(String) -> ConstraintError {
+            move |message| ConstraintError::new(move || format!("[{}] {}", tag, message))
+        }
+
+        #[test]
+        fn should_take_precedence_over_the_scope_generator() {
+            let scope = StringScope::new_with_exception_generator(
+                "Size".to_string(),
+                Arc::new(Mutex::new(Vec::new())),
+                Box::new(tagged("scope")),
+            );
+
+            scope.must_with_exception(vec![1], HaveSize::with_exact_size(2), tagged("rule"));
+            scope.must_not_with_exception(vec![1], HaveSize::with_exact_size(1), tagged("other"));
+            scope.must(vec![1], HaveSize::with_exact_size(2));
+
+            let messages: Vec<String> = scope
+                .results
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|result| result.failure().unwrap().message())
+                .collect();
+            expect!(messages).to(be_equal_to(vec![
+                "[rule] Size".to_string(),
+                "[other] Size".to_string(),
+                "[scope] Size".to_string(),
+            ]));
+        }
+
+        #[test]
+        fn should_use_the_generator_of_an_adapted_constraint() {
+            let scope = create_string_scope("Size".to_string());
+
+            scope.must(vec![1], with_exception(HaveSize::with_exact_size(2), tagged("rule")));
+
+            let results = scope.results.lock().unwrap();
+            expect!(results[0].failure().unwrap().message()).to(be_equal_to("[rule] Size"));
+        }
+    }
+
+    mod when_chaining_dependent_rules {
+        use super::*;
+
+        #[test]
+        fn should_skip_the_dependent_rules_if_the_guard_fails() {
+            let scope = create_string_scope("Size".to_string());
+            let evaluated = std::cell::Cell::new(false);
+
+            let outcome = scope.must(vec![1, 2, 3], HaveSize::with_exact_size(2)).then(|s| {
+                evaluated.set(true);
+                s.must(vec![1, 2, 3], HaveSize::with_exact_size(4))
+            });
+
+            expect!(outcome.passed()).to(be_false());
+            expect!(evaluated.get()).to(be_false());
+            expect!(scope.results.lock().unwrap().len()).to(be_equal_to(1));
+        }
+
+        #[test]
+        fn should_run_the_dependent_rules_if_the_guard_passes() {
+            let scope = create_string_scope("Size".to_string());
+
+            let outcome = scope
+                .must(vec![1, 2, 3], HaveSize::with_exact_size(3))
+                .then(|s| s.must_not(vec![1, 2, 3], HaveSize::with_exact_size(3)))
+                .then(|s| s.constraint(|| false));
+
+            expect!(outcome.passed()).to(be_false());
+            let results = scope.results.lock().unwrap();
+            expect!(results.len()).to(be_equal_to(2));
+            expect!(results[0].is_passed()).to(be_true());
+            expect!(results[1].is_failed()).to(be_true());
+        }
+    }
+
+    mod when_validating_a_should_clause {
+        use super::*;
+
+        #[test]
+        fn should_record_failures_as_warnings() {
+            let scope = create_string_scope("Size".to_string());
+
+            scope.should(vec![1, 2, 3], HaveSize::with_exact_size(2));
+            scope.should_not(vec![1, 2, 3], HaveSize::with_exact_size(2));
+
+            expect!(scope.results.lock().unwrap().len()).to(be_equal_to(0));
+            let warnings = scope.warnings.lock().unwrap();
+            expect!(warnings.len()).to(be_equal_to(1));
+            expect!(warnings[0].message()).to(be_equal_to("Size: expected size 2, got 3"));
+        }
+
+        #[test]
+        fn should_record_skipped_rules_as_warnings() {
+            let scope = create_string_scope("Size".to_string()).with_deadline(Some(Instant::now()));
+
+            scope.should(vec![1, 2, 3], HaveSize::with_exact_size(3));
+
+            expect!(scope.results.lock().unwrap().len()).to(be_equal_to(0));
+            expect!(scope.warnings.lock().unwrap()[0].message())
+                .to(be_equal_to("Size: skipped after the deadline passed"));
+        }
     }
 
     /// Creates a new instance of `StringScope` with a given message.
@@ -220,6 +1251,6 @@ mod tests {
     fn collection_have_size(
         size: impl Strategy<Value=usize> + 'static
     ) -> impl Strategy<Value=HaveSize> {
-        size.prop_map(|size| HaveSize::with_exact_size(size))
+        size.prop_map(HaveSize::with_exact_size)
     }
 }
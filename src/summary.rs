@@ -0,0 +1,222 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Aggregated statistics of the rules run by a scope.
+//!
+//! A `Summary` counts the outcomes of the rules instead of keeping them, so it is cheap to build,
+//! to copy around and to export to dashboards or metrics, much like the statistics object of a
+//! Kotlin test runner.
+use crate::rule_result::RuleResult;
+use crate::string_scope::BlockResult;
+
+/// The counts of evaluated, passed, failed and skipped rules of a scope, with a breakdown per
+/// group of rules.
+///
+/// Skipped rules, those not evaluated because the deadline had passed, are counted apart from the
+/// evaluated ones, but they make the summary invalid, like they do with `RustrictScope::is_valid`.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// let scope = RustrictScope::new();
+/// scope.validate_string("Roles", |s| {
+///     s.must(vec!["admin"], NotBeEmpty);
+///     s.must(vec!["admin", "admin"], HaveNoDuplicates);
+/// });
+/// scope.validate_string("Tags", |s| s.must(vec!["a"], NotBeEmpty));
+///
+/// let summary = scope.summary();
+/// assert_eq!((summary.evaluated(), summary.passed(), summary.failed()), (3, 2, 1));
+/// assert_eq!(summary.group("Roles").unwrap().failed(), 1);
+/// assert!(!summary.is_valid());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    counts: Counts,
+    groups: Vec<GroupSummary>,
+}
+
+impl Summary {
+    /// Builds a summary from the results of a scope and the blocks that produced them.
+    ///
+    /// Blocks sharing a message are merged into a single group, placed where the first of them
+    /// ran.
+    pub(crate) fn new(results: &[RuleResult], blocks: &[BlockResult]) -> Self {
+        let mut groups: Vec<GroupSummary> = Vec::new();
+        for block in blocks {
+            let position = groups.iter().position(|group| group.message == block.message());
+            let group = match position {
+                Some(position) => &mut groups[position],
+                None => {
+                    groups.push(GroupSummary {
+                        message: block.message().to_string(),
+                        counts: Counts::default(),
+                    });
+                    groups.last_mut().unwrap()
+                }
+            };
+            group.counts.add_all(block.results());
+        }
+        let mut counts = Counts::default();
+        counts.add_all(results);
+        Summary { counts, groups }
+    }
+
+    /// Returns the number of rules evaluated so far, leaving out the skipped ones.
+    pub fn evaluated(&self) -> usize {
+        self.counts.evaluated
+    }
+
+    /// Returns the number of rules that passed.
+    pub fn passed(&self) -> usize {
+        self.counts.evaluated - self.counts.failed
+    }
+
+    /// Returns the number of rules that failed.
+    pub fn failed(&self) -> usize {
+        self.counts.failed
+    }
+
+    /// Returns the number of rules skipped because the deadline had passed.
+    pub fn skipped(&self) -> usize {
+        self.counts.skipped
+    }
+
+    /// Returns `true` if no rule failed or was skipped.
+    pub fn is_valid(&self) -> bool {
+        self.counts.is_valid()
+    }
+
+    /// Returns the summary of each group of rules, in the order the groups first ran.
+    pub fn groups(&self) -> &[GroupSummary] {
+        &self.groups
+    }
+
+    /// Returns the summary of the group with the given message, if any of its rules ran.
+    pub fn group(&self, message: &str) -> Option<&GroupSummary> {
+        self.groups.iter().find(|group| group.message == message)
+    }
+}
+
+/// The counts of the rules declared in the blocks that share a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupSummary {
+    message: String,
+    counts: Counts,
+}
+
+impl GroupSummary {
+    /// Returns the message of the blocks of the group.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the number of rules of the group that were evaluated, leaving out the skipped ones.
+    pub fn evaluated(&self) -> usize {
+        self.counts.evaluated
+    }
+
+    /// Returns the number of rules of the group that passed.
+    pub fn passed(&self) -> usize {
+        self.counts.evaluated - self.counts.failed
+    }
+
+    /// Returns the number of rules of the group that failed.
+    pub fn failed(&self) -> usize {
+        self.counts.failed
+    }
+
+    /// Returns the number of rules of the group skipped because the deadline had passed.
+    pub fn skipped(&self) -> usize {
+        self.counts.skipped
+    }
+
+    /// Returns `true` if no rule of the group failed or was skipped.
+    pub fn is_valid(&self) -> bool {
+        self.counts.is_valid()
+    }
+}
+
+/// The counters shared by `Summary` and `GroupSummary`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Counts {
+    evaluated: usize,
+    failed: usize,
+    skipped: usize,
+}
+
+impl Counts {
+    /// Counts the given results.
+    fn add_all(&mut self, results: &[RuleResult]) {
+        for result in results {
+            match result {
+                RuleResult::Passed => self.evaluated += 1,
+                RuleResult::Failed(_) => {
+                    self.evaluated += 1;
+                    self.failed += 1;
+                }
+                RuleResult::Skipped(_) => self.skipped += 1,
+            }
+        }
+    }
+
+    /// Returns `true` if no rule failed or was skipped.
+    fn is_valid(&self) -> bool {
+        self.failed == 0 && self.skipped == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constraints::collections::be_empty::NotBeEmpty;
+    use crate::rustrict_scope::RustrictScope;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+    use std::time::Duration;
+
+    proptest! {
+        #[test]
+        fn should_count_every_rule(sizes in prop::collection::vec(0usize..3, 0..20)) {
+            let scope = RustrictScope::new();
+            for size in &sizes {
+                scope.validate_string("Items", |s| s.must(vec![0; *size], NotBeEmpty));
+            }
+
+            let summary = scope.summary();
+
+            let failed = sizes.iter().filter(|size| **size == 0).count();
+            expect!(summary.evaluated()).to(be_equal_to(sizes.len()));
+            expect!(summary.failed()).to(be_equal_to(failed));
+            expect!(summary.passed()).to(be_equal_to(sizes.len() - failed));
+            expect!(summary.is_valid()).to(be_equal_to(scope.is_valid()));
+        }
+    }
+
+    #[test]
+    fn should_merge_groups_sharing_a_message() {
+        let scope = RustrictScope::new();
+        scope.validate_string("Roles", |s| s.must(vec!["admin"], NotBeEmpty));
+        scope.validate_string("Tags", |s| s.must(Vec::<&str>::new(), NotBeEmpty));
+        scope.validate_string("Roles", |s| s.must(Vec::<&str>::new(), NotBeEmpty));
+
+        let summary = scope.summary();
+
+        let messages: Vec<&str> = summary.groups().iter().map(|group| group.message()).collect();
+        expect!(messages).to(be_equal_to(vec!["Roles", "Tags"]));
+        let roles = summary.group("Roles").unwrap();
+        expect!((roles.evaluated(), roles.passed(), roles.failed())).to(be_equal_to((2, 1, 1)));
+    }
+
+    #[test]
+    fn should_count_skipped_rules_apart_from_failures() {
+        let scope = RustrictScope::new().with_deadline(Duration::ZERO);
+        scope.validate_string("Roles", |s| s.must(vec!["admin"], NotBeEmpty));
+
+        let summary = scope.summary();
+
+        expect!((summary.evaluated(), summary.failed(), summary.skipped())).to(be_equal_to((0, 0, 1)));
+        expect!(summary.is_valid()).to(be_false());
+        expect!(summary.group("Roles").unwrap().skipped()).to(be_equal_to(1));
+    }
+}
@@ -0,0 +1,349 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Assertion helpers for testing code that uses Rustrict.
+//!
+//! This module is available with the `testing` feature. It provides
+//! [expectest](https://docs.rs/expectest)-compatible matchers to assert on the results of a
+//! validation scope, similar to the custom matchers you would write for Kotest in Kotlin:
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! # use rustrict::testing::*;
+//! # use expectest::prelude::*;
+//! # let scope = RustrictScope::new();
+//! # scope.validate_string("Size must be 3", |s| s.must(vec![1, 2], HaveSize::with_exact_size(3)));
+//! # struct User;
+//! # impl Validate for User {
+//! #     fn constraints(&self, _scope: &mut RustrictScope) {}
+//! # }
+//! # let user = User;
+//! expect!(scope.results()).to(have_failures(1));
+//! expect!(scope.results()).to(fail_with_message("Size must be 3: expected size 3, got 2"));
+//! expect!(user.validate()).to(have_failures(0));
+//! ```
+use crate::errors::composite_error::CompositeError;
+use crate::errors::constraint_error::ConstraintError;
+use crate::rule_result::RuleResult;
+use expectest::core::{Join, Matcher};
+use expectest::prelude::*;
+use std::panic::{catch_unwind, UnwindSafe};
+
+/// Asserts that running `f` panics with a payload of type `T`.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::testing::*;
+/// panic_with_type::<_, &'static str>(|| panic!("boom"));
+/// ```
+pub fn panic_with_type<F, T>(f: F)
+where
+    F: FnOnce() + UnwindSafe,
+    T: 'static + std::fmt::Debug,
+{
+    let result = catch_unwind(f);
+    match result {
+        Ok(_) => expect!(false).to(be_true()), // Expecting a panic, but none occurred
+        Err(err) => expect!(err.is::<T>()).to(be_true()), // Expecting a panic of type T
+    };
+}
+
+/// A struct representing a value to be checked for containment within another value.
+///
+/// In Rust, `Contains<T>` is a generic struct that stores a value of type `T`. This value
+/// can then be used to check if it is contained within another value, such as a `String`.
+/// This concept is similar to creating a custom matcher in Kotlin for checking if a string
+/// contains a substring.
+///
+/// # Type Parameters:
+/// - `T`: The type of the value that you want to check for containment. For example, if you
+///   want to check if a `String` contains another `String`, `T` would be `String`.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::testing::*;
+/// # use expectest::core::Matcher;
+/// let contains_hello = contain("Hello".to_string());
+/// assert!(contains_hello.matches(&"Hello, world!".to_string()));
+/// ```
+///
+/// In this example, `contains_hello` is a `Contains<String>` instance that checks if a given
+/// `String` contains the word "Hello".
+pub struct Contains<T> {
+    value: T,
+}
+
+/// A function to create a `Contains<T>` instance.
+///
+/// This function serves as a constructor for `Contains<T>`, making it easy to create
+/// instances of `Contains` without having to explicitly write out the struct syntax.
+/// It's similar to factory functions you might define in Kotlin.
+///
+/// # Parameters:
+/// - `value`: The value that you want to check for containment. This value is stored
+///   inside the `Contains` struct.
+///
+/// # Returns:
+/// - A `Contains<T>` instance that holds the provided value.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::testing::*;
+/// # use expectest::core::Matcher;
+/// let contains_rust = contain("Rust".to_string());
+/// assert!(contains_rust.matches(&"Learning Rust is fun!".to_string()));
+/// ```
+///
+/// This example shows how to create a `Contains<String>` instance using the `contain`
+/// function and then use it to check if a `String` contains the word "Rust".
+pub fn contain<T>(value: T) -> Contains<T> {
+    Contains { value }
+}
+
+/// Implementation of the `Matcher` trait for `Contains<String>`.
+///
+/// This implementation allows `Contains<String>` to be used as a matcher in Rust's
+/// testing framework. The `Matcher` trait defines methods for checking if a condition
+/// is met (`matches`) and for generating failure messages (`failure_message`).
+/// This is conceptually similar to writing custom assertions or matchers in Kotlin.
+///
+/// # Methods:
+///
+/// - `failure_message(&self, join: Join, actual: &String) -> String`:
+///   Generates a detailed failure message if the `actual` string does not contain
+///   the expected value. This method is useful for providing clear feedback in tests
+///   when an assertion fails. It works similarly to custom failure messages you might
+///   write in Kotlin testing frameworks.
+///
+/// - `matches(&self, actual: &String) -> bool`:
+///   Checks if the `actual` string contains the expected value stored in `self.value`.
+///   This method returns `true` if the value is found, and `false` otherwise. It's
+///   analogous to using `contains` or similar methods in Kotlin to check for substrings.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::testing::*;
+/// # use expectest::core::Matcher;
+/// let matcher = contain("Rust".to_string());
+/// assert!(matcher.matches(&"Learning Rust is fun!".to_string()));
+/// ```
+///
+/// In this example, `matches` will return `true` because the string "Rust" is contained
+/// within the given `actual` string.
+impl Matcher<String, String> for Contains<String> {
+    fn failure_message(&self, join: Join, actual: &String) -> String {
+        if join.is_assertion() {
+            format!(
+                "expected {} contain <{:?}>, at index <{:?}>. Got <{:?}>",
+                join,
+                self.value,
+                actual.find(&self.value),
+                actual
+            )
+        } else {
+            format!("expected {} contain <{:?}>", join, self.value)
+        }
+    }
+
+    fn matches(&self, actual: &String) -> bool {
+        actual.contains(&self.value)
+    }
+}
+
+/// A matcher that checks the number of failed rules in a set of validation results.
+pub struct HaveFailures {
+    count: usize,
+}
+
+/// Creates a matcher that passes if exactly `count` rules failed.
+///
+/// It can be used with the results of a scope (`Vec<RuleResult>`) or with the
+/// outcome of a validation (`Result<(), CompositeError>`).
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # use rustrict::testing::*;
+/// # use expectest::prelude::*;
+/// # let scope = RustrictScope::new();
+/// # scope.validate_string("Size must be 1", |s| s.must(vec![1, 2], HaveSize::with_exact_size(1)));
+/// # scope.validate_string("Size must be 3", |s| s.must(vec![1, 2], HaveSize::with_exact_size(3)));
+/// expect!(scope.results()).to(have_failures(2));
+/// ```
+pub fn have_failures(count: usize) -> HaveFailures {
+    HaveFailures { count }
+}
+
+impl HaveFailures {
+    fn failure_message_for(&self, join: Join, actual: usize) -> String {
+        if join.is_assertion() {
+            format!("expected {} have <{}> failures, got <{}>", join, self.count, actual)
+        } else {
+            format!("expected {} have <{}> failures", join, self.count)
+        }
+    }
+}
+
+impl Matcher<Vec<RuleResult>, ()> for HaveFailures {
+    fn failure_message(&self, join: Join, actual: &Vec<RuleResult>) -> String {
+        self.failure_message_for(join, failure_messages(actual).len())
+    }
+
+    fn matches(&self, actual: &Vec<RuleResult>) -> bool {
+        failure_messages(actual).len() == self.count
+    }
+}
+
+impl Matcher<Result<(), CompositeError>, ()> for HaveFailures {
+    fn failure_message(&self, join: Join, actual: &Result<(), CompositeError>) -> String {
+        self.failure_message_for(join, composite_messages(actual).len())
+    }
+
+    fn matches(&self, actual: &Result<(), CompositeError>) -> bool {
+        composite_messages(actual).len() == self.count
+    }
+}
+
+/// A matcher that checks that some failed rule produced a given message.
+pub struct FailWithMessage {
+    message: String,
+}
+
+/// Creates a matcher that passes if at least one failed rule has exactly the given message.
+///
+/// It can be used with the results of a scope (`Vec<RuleResult>`) or with the
+/// outcome of a validation (`Result<(), CompositeError>`).
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # use rustrict::testing::*;
+/// # use expectest::prelude::*;
+/// # let scope = RustrictScope::new();
+/// # scope.validate_string("Size must be 3", |s| s.must(vec![1, 2], HaveSize::with_exact_size(3)));
+/// expect!(scope.results()).to(fail_with_message("Size must be 3: expected size 3, got 2"));
+/// ```
+pub fn fail_with_message(message: impl Into<String>) -> FailWithMessage {
+    FailWithMessage {
+        message: message.into(),
+    }
+}
+
+impl FailWithMessage {
+    fn failure_message_for(&self, join: Join, actual: Vec<String>) -> String {
+        if join.is_assertion() {
+            format!("expected {} fail with message <{:?}>, got <{:?}>", join, self.message, actual)
+        } else {
+            format!("expected {} fail with message <{:?}>", join, self.message)
+        }
+    }
+}
+
+impl Matcher<Vec<RuleResult>, ()> for FailWithMessage {
+    fn failure_message(&self, join: Join, actual: &Vec<RuleResult>) -> String {
+        self.failure_message_for(join, failure_messages(actual))
+    }
+
+    fn matches(&self, actual: &Vec<RuleResult>) -> bool {
+        failure_messages(actual).contains(&self.message)
+    }
+}
+
+impl Matcher<Result<(), CompositeError>, ()> for FailWithMessage {
+    fn failure_message(&self, join: Join, actual: &Result<(), CompositeError>) -> String {
+        self.failure_message_for(join, composite_messages(actual))
+    }
+
+    fn matches(&self, actual: &Result<(), CompositeError>) -> bool {
+        composite_messages(actual).contains(&self.message)
+    }
+}
+
+/// Returns the messages of the failed results, in order.
+fn failure_messages(results: &[RuleResult]) -> Vec<String> {
+    results
+        .iter()
+        .filter_map(RuleResult::failure)
+        .map(ConstraintError::message)
+        .collect()
+}
+
+/// Returns the messages of the errors aggregated in a failed validation, in order.
+///
+/// Constraint errors contribute their message alone, without the hint shown by `Display`, like
+/// in the results of a scope.
+fn composite_messages(result: &Result<(), CompositeError>) -> Vec<String> {
+    match result {
+        Ok(()) => Vec::new(),
+        Err(error) => error
+            .errors()
+            .iter()
+            .map(|e| match e.downcast_ref::<ConstraintError>() {
+                Some(error) => error.message(),
+                None => e.to_string(),
+            })
+            .collect(),
+    }
+}
+
+/// Records one rule per size in the scope, checking whether a three-element collection has that
+/// size.
+///
+/// Shared by the tests of the scopes and of the matchers of this module.
+#[cfg(test)]
+pub(crate) fn validate_sizes(scope: &crate::rustrict_scope::RustrictScope, sizes: &[usize]) {
+    use crate::constraints::collections::have_size::HaveSize;
+
+    for size in sizes {
+        scope.validate_string(format!("Size must be {}", size), |s| {
+            s.must(vec![1, 2, 3], HaveSize::with_exact_size(*size));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rustrict_scope::RustrictScope;
+    use proptest::prelude::*;
+
+    /// Creates a scope with one rule per size, see `validate_sizes`.
+    fn scope_with_sizes(sizes: &[usize]) -> RustrictScope {
+        let scope = RustrictScope::new();
+        validate_sizes(&scope, sizes);
+        scope
+    }
+
+    proptest! {
+        #[test]
+        fn should_count_the_failures(sizes in proptest::collection::vec(0usize..6, 0..20)) {
+            let expected = sizes.iter().filter(|size| **size != 3).count();
+            let scope = scope_with_sizes(&sizes);
+
+            expect!(scope.results()).to(have_failures(expected));
+            expect!(scope.into_result()).to(have_failures(expected));
+        }
+    }
+
+    #[test]
+    fn should_find_the_failure_message() {
+        let scope = scope_with_sizes(&[3, 2]);
+
+        expect!(scope.results()).to(fail_with_message("Size must be 2: expected size 2, got 3"));
+        expect!(scope.results()).not_to(fail_with_message("Size must be 3: expected size 3, got 3"));
+        expect!(scope.into_result()).to(fail_with_message("Size must be 2: expected size 2, got 3"));
+    }
+
+    #[test]
+    fn should_describe_a_wrong_failure_count() {
+        expect(scope_with_sizes(&[2]).results())
+            .to(have_failures(0))
+            .assert_eq_message("expected to have <0> failures, got <1>");
+    }
+
+    #[test]
+    fn should_detect_panics_of_the_given_type() {
+        panic_with_type::<_, &'static str>(|| panic!("boom"));
+    }
+}
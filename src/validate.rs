@@ -0,0 +1,208 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::errors::composite_error::CompositeError;
+use crate::rustrict_scope::RustrictScope;
+
+/// A trait for types that know how to validate themselves.
+///
+/// Implementors declare their rules in `constraints`, and get a `validate` method that runs them
+/// in a fresh `RustrictScope`. Nested values that also implement `Validate` can be checked from
+/// within `constraints` through `RustrictScope::must_be_valid`, so the rules of a whole domain
+/// model compose naturally. This plays the role of a class with a `validate()` method built on
+/// the Kotlin DSL.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # struct User;
+/// # impl Validate for User {
+/// #     fn constraints(&self, _scope: &mut RustrictScope) {}
+/// # }
+/// struct Team {
+///     members: Vec<String>,
+///     leader: User,
+/// }
+///
+/// impl Validate for Team {
+///     fn constraints(&self, scope: &mut RustrictScope) {
+///         scope.validate_string("Team must have members", |s| {
+///             s.must(self.members.clone(), NotBeEmpty);
+///         });
+///         scope.must_be_valid(&self.leader);
+///     }
+/// }
+///
+/// # let team = Team { members: vec!["Ana".to_string()], leader: User };
+/// match team.validate() {
+///     Ok(()) => println!("Valid team"),
+///     Err(e) => println!("{}", e),
+/// }
+/// ```
+pub trait Validate {
+    /// Declares the rules that a value of this type must satisfy.
+    ///
+    /// # Parameters:
+    /// - `scope`: The scope in which the rules are recorded.
+    fn constraints(&self, scope: &mut RustrictScope);
+
+    /// Runs the rules declared in `constraints` in a new scope.
+    ///
+    /// The value itself is validated through `RustrictScope::must_be_valid`, so cycles leading
+    /// back to it are detected.
+    ///
+    /// # Returns:
+    /// - `Ok(())`: If every rule passed.
+    /// - `Err(CompositeError)`: With every failure, including those of nested values.
+    fn validate(&self) -> Result<(), CompositeError> {
+        let mut scope = RustrictScope::new();
+        scope.must_be_valid(self);
+        scope.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::be_empty::NotBeEmpty;
+    use crate::constraints::collections::have_size::HaveSize;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    struct Address {
+        lines: Vec<String>,
+    }
+
+    impl Validate for Address {
+        fn constraints(&self, scope: &mut RustrictScope) {
+            scope.validate_string("Address must have two lines", |s| {
+                s.must(self.lines.clone(), HaveSize::with_exact_size(2));
+            });
+        }
+    }
+
+    struct User {
+        roles: Vec<String>,
+        address: Address,
+    }
+
+    impl Validate for User {
+        fn constraints(&self, scope: &mut RustrictScope) {
+            scope.validate_string("Roles must not be empty", |s| {
+                s.must(self.roles.clone(), NotBeEmpty);
+            });
+            scope.must_be_valid(&self.address);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn should_collect_failures_from_nested_values(
+            roles in proptest::collection::vec("[a-z]{1,10}", 0..3),
+            lines in proptest::collection::vec("[a-z]{1,10}", 0..4)
+        ) {
+            let expected_failures =
+                usize::from(roles.is_empty()) + usize::from(lines.len() != 2);
+            let user = User { roles, address: Address { lines } };
+
+            let failures = match user.validate() {
+                Ok(()) => 0,
+                Err(error) => error.errors().len(),
+            };
+            expect!(failures).to(be_equal_to(expected_failures));
+        }
+    }
+
+    /// A node of a linked list whose links can be tied into a cycle.
+    struct Node {
+        next: std::cell::Cell<Option<&'static Node>>,
+    }
+
+    impl Validate for Node {
+        fn constraints(&self, scope: &mut RustrictScope) {
+            if let Some(next) = self.next.get() {
+                scope.must_be_valid(next);
+            }
+        }
+    }
+
+    /// Builds a list of the given length, leaking its nodes so they can reference each other.
+    fn list(length: usize) -> Vec<&'static Node> {
+        let nodes: Vec<&'static Node> = (0..length)
+            .map(|_| &*Box::leak(Box::new(Node { next: std::cell::Cell::new(None) })))
+            .collect();
+        for pair in nodes.windows(2) {
+            pair[0].next.set(Some(pair[1]));
+        }
+        nodes
+    }
+
+    #[test]
+    fn should_detect_cycles_in_the_object_graph() {
+        let nodes = list(3);
+        nodes[2].next.set(Some(nodes[0]));
+
+        let error = nodes[0].validate().unwrap_err();
+
+        expect!(error.errors().len()).to(be_equal_to(1));
+        expect!(error.errors()[0].to_string().starts_with("Cycle detected")).to(be_true());
+    }
+
+    #[test]
+    fn should_stop_at_the_maximum_depth() {
+        let nodes = list(5);
+        let mut scope = RustrictScope::new().with_max_depth(3);
+
+        scope.must_be_valid(nodes[0]);
+
+        let failures = scope.failures();
+        expect!(failures.len()).to(be_equal_to(1));
+        expect!(failures[0].message().starts_with("Maximum nesting depth of 3 exceeded"))
+            .to(be_true());
+        expect!(list(3)[0].validate()).to(be_ok());
+    }
+
+    /// A value whose rules panic the first time they run.
+    struct Flaky {
+        panicked: std::cell::Cell<bool>,
+    }
+
+    impl Validate for Flaky {
+        fn constraints(&self, _scope: &mut RustrictScope) {
+            if !self.panicked.replace(true) {
+                panic!("rules of a flaky value");
+            }
+        }
+    }
+
+    #[test]
+    fn should_leave_the_nesting_stack_clean_after_a_panic() {
+        let flaky = Flaky {
+            panicked: std::cell::Cell::new(false),
+        };
+        let mut scope = RustrictScope::new();
+
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            scope.must_be_valid(&flaky);
+        }));
+        scope.must_be_valid(&flaky);
+
+        expect!(outcome).to(be_err());
+        expect!(scope.is_valid()).to(be_true());
+    }
+
+    #[test]
+    fn should_report_the_nested_failure_message() {
+        let user = User {
+            roles: vec!["admin".to_string()],
+            address: Address { lines: vec![] },
+        };
+
+        let error = user.validate().unwrap_err();
+
+        expect!(error.errors()[0].to_string()).to(be_equal_to(
+            "Address must have two lines: expected size 2, got 0 (hint: add 2 elements)",
+        ));
+    }
+}
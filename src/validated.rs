@@ -0,0 +1,171 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Values that are known to have passed their validation.
+//!
+//! `Validated<T>` can only be obtained by running rules against a value, so a function that takes
+//! a `Validated<Email>` is statically sure that the email was checked, instead of trusting every
+//! caller to remember it. This is the "parse, don't validate" approach, comparable to a Kotlin
+//! value class with a private constructor and a validating factory.
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! fn invite(email: &Validated<String>) -> String {
+//!     format!("Invitation sent to {}", email)
+//! }
+//!
+//! let email = rustrict::validated("ada@example.com".to_string(), |scope, email| {
+//!     scope.validate_string("Email must be valid", |s| s.must(email, MatchGlob::new("*@*.*")));
+//! })?;
+//! assert_eq!(invite(&email), "Invitation sent to ada@example.com");
+//! # Ok::<(), CompositeError>(())
+//! ```
+use crate::errors::composite_error::CompositeError;
+use crate::rustrict_scope::RustrictScope;
+use crate::validate::Validate;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+/// A value that passed the rules it was validated with.
+///
+/// The wrapped value can be read through `Deref` and `AsRef`, but not changed, since a change
+/// could break the rules. Use `into_inner` to take it back, giving up the guarantee.
+///
+/// # Type Parameters:
+/// - `T`: The type of the validated value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Validated<T>(T);
+
+impl<T: Validate> Validated<T> {
+    /// Validates a value with the rules of its `Validate` implementation.
+    ///
+    /// # Returns:
+    /// - `Ok(Validated<T>)`: The wrapped value, if every rule passed.
+    /// - `Err(CompositeError)`: With every failure, including those of nested values.
+    ///
+    /// # Example:
+    /// ```rust
+    /// # use rustrict::prelude::*;
+    /// struct Team {
+    ///     members: Vec<String>,
+    /// }
+    ///
+    /// impl Validate for Team {
+    ///     fn constraints(&self, scope: &mut RustrictScope) {
+    ///         scope.validate_string("Team must have members", |s| {
+    ///             s.must(&self.members, NotBeEmpty);
+    ///         });
+    ///     }
+    /// }
+    ///
+    /// assert!(Validated::new(Team { members: vec![] }).is_err());
+    /// ```
+    pub fn new(value: T) -> Result<Self, CompositeError> {
+        value.validate().map(|()| Validated(value))
+    }
+}
+
+impl<T> Validated<T> {
+    /// Unwraps the validated value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Validated<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Display> Display for Validated<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Validates a value with the given rules, wrapping it in a `Validated` if all of them pass.
+///
+/// # Parameters:
+/// - `value`: The value to validate.
+/// - `rules`: A closure that declares the rules of the value through a fresh scope.
+///
+/// # Returns:
+/// - `Ok(Validated<T>)`: The wrapped value, if every rule passed.
+/// - `Err(CompositeError)`: With the failures of every rule that did not pass.
+pub fn validated<T, F>(value: T, rules: F) -> Result<Validated<T>, CompositeError>
+where
+    F: FnOnce(&mut RustrictScope, &T),
+{
+    let mut scope = RustrictScope::new();
+    rules(&mut scope, &value);
+    scope.into_result().map(|()| Validated(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::be_empty::NotBeEmpty;
+    use crate::constraints::collections::have_no_duplicates::HaveNoDuplicates;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    fn validated_roles(roles: Vec<u8>) -> Result<Validated<Vec<u8>>, CompositeError> {
+        validated(roles, |scope, roles| {
+            scope.validate_string("Roles must not be empty", |s| s.must(roles, NotBeEmpty));
+            scope.validate_string("Roles must be unique", |s| s.must(roles, HaveNoDuplicates));
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn should_wrap_only_valid_values(roles: Vec<u8>) {
+            let expected = !roles.is_empty()
+                && HaveNoDuplicates.first_duplicate_index(&roles).is_none();
+
+            let result = validated_roles(roles.clone());
+
+            expect!(result.is_ok()).to(be_equal_to(expected));
+            if let Ok(wrapped) = result {
+                expect!(wrapped.into_inner()).to(be_equal_to(roles));
+            }
+        }
+    }
+
+    #[test]
+    fn should_report_the_failures() {
+        let error = validated_roles(vec![]).unwrap_err();
+
+        expect!(error.errors().len()).to(be_equal_to(1));
+    }
+
+    #[derive(Debug)]
+    struct Team {
+        members: Vec<String>,
+    }
+
+    impl Validate for Team {
+        fn constraints(&self, scope: &mut RustrictScope) {
+            scope.validate_string("Team must have members", |s| s.must(&self.members, NotBeEmpty));
+        }
+    }
+
+    #[test]
+    fn should_validate_with_the_rules_of_the_type() {
+        let team = Validated::new(Team {
+            members: vec!["Ana".to_string()],
+        });
+
+        expect!(team.map(|team| team.members.len())).to(be_ok().value(1));
+        expect!(Validated::new(Team { members: vec![] })).to(be_err());
+    }
+}
@@ -0,0 +1,179 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::errors::composite_error::CompositeError;
+use crate::rustrict_scope::RustrictScope;
+
+/// A builder that validates the value it constructs.
+///
+/// `ValidatedBuilder` holds a value under construction together with the rules that it must
+/// satisfy. Each setter records the constraints for the field it changes, and `build` runs all of
+/// them in a single `RustrictScope`, so the value is only handed out once it is known to be valid.
+/// This is the "parse, don't validate" approach: construction is the only way in, and it checks
+/// everything.
+///
+/// # Type Parameters:
+/// - `T`: The type of the value being built.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # #[derive(Default)]
+/// # struct User { roles: Vec<String> }
+/// # fn main() -> Result<(), CompositeError> {
+/// let user = ValidatedBuilder::new(User::default())
+///     .set(
+///         |user| user.roles = vec!["admin".to_string()],
+///         |user, scope| scope.validate_string("Roles must not be empty", |s| {
+///             s.must(user.roles.clone(), NotBeEmpty)
+///         }),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ValidatedBuilder<T> {
+    value: T,
+    rules: Vec<Rule<T>>,
+}
+
+/// A rule recorded by a `ValidatedBuilder`, evaluated against the built value.
+type Rule<T> = Box<dyn FnOnce(&T, &mut RustrictScope)>;
+
+impl<T> ValidatedBuilder<T> {
+    /// Creates a builder starting from the given value.
+    ///
+    /// # Parameters:
+    /// - `value`: The initial state of the value being built.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Changes the value being built and records the rule that the change must satisfy.
+    ///
+    /// The rule is not evaluated until `build` is called, so it sees the final state of the value.
+    ///
+    /// # Parameters:
+    /// - `setter`: A closure that updates the value.
+    /// - `rule`: A closure that declares the constraints for the updated value.
+    pub fn set<S, R>(mut self, setter: S, rule: R) -> Self
+    where
+        S: FnOnce(&mut T),
+        R: FnOnce(&T, &mut RustrictScope) + 'static,
+    {
+        setter(&mut self.value);
+        self.constraint(rule)
+    }
+
+    /// Records a rule over the whole value, such as a relation between several fields.
+    ///
+    /// # Parameters:
+    /// - `rule`: A closure that declares the constraints for the value.
+    pub fn constraint<R>(mut self, rule: R) -> Self
+    where
+        R: FnOnce(&T, &mut RustrictScope) + 'static,
+    {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Runs every recorded rule and returns the value if all of them passed.
+    ///
+    /// # Returns:
+    /// - `Ok(T)`: The built value, if every rule passed.
+    /// - `Err(CompositeError)`: With the failures of every rule that did not pass.
+    pub fn build(self) -> Result<T, CompositeError> {
+        let mut scope = RustrictScope::new();
+        for rule in self.rules {
+            rule(&self.value, &mut scope);
+        }
+        scope.into_result().map(|()| self.value)
+    }
+}
+
+impl<T: Default> Default for ValidatedBuilder<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::collections::be_empty::NotBeEmpty;
+    use crate::constraints::collections::have_size::HaveSize;
+    use expectest::prelude::*;
+    use proptest::prelude::*;
+
+    #[derive(Debug, Default, PartialEq, Clone)]
+    struct Team {
+        members: Vec<String>,
+        leaders: Vec<String>,
+    }
+
+    fn team_builder(members: Vec<String>, leaders: Vec<String>) -> ValidatedBuilder<Team> {
+        ValidatedBuilder::<Team>::default()
+            .set(
+                |team| team.members = members,
+                |team, scope| {
+                    scope.validate_string("Team must have members", |s| {
+                        s.must(team.members.clone(), NotBeEmpty)
+                    })
+                },
+            )
+            .set(
+                |team| team.leaders = leaders,
+                |team, scope| {
+                    scope.validate_string("Team must have one leader", |s| {
+                        s.must(team.leaders.clone(), HaveSize::with_exact_size(1))
+                    })
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn should_build_the_value_when_every_rule_passes(
+            members in proptest::collection::vec("[a-z]{1,10}", 1..5),
+            leader in "[a-z]{1,10}"
+        ) {
+            let result = team_builder(members.clone(), vec![leader.clone()]).build();
+
+            expect!(result).to(be_ok().value(Team { members, leaders: vec![leader] }));
+        }
+
+        #[test]
+        fn should_report_every_failed_rule(
+            members in proptest::collection::vec("[a-z]{1,10}", 0..3),
+            leaders in proptest::collection::vec("[a-z]{1,10}", 0..3)
+        ) {
+            let expected_failures =
+                usize::from(members.is_empty()) + usize::from(leaders.len() != 1);
+
+            let failures = match team_builder(members, leaders).build() {
+                Ok(_) => 0,
+                Err(error) => error.errors().len(),
+            };
+
+            expect!(failures).to(be_equal_to(expected_failures));
+        }
+    }
+
+    #[test]
+    fn should_evaluate_rules_against_the_final_value() {
+        let result = ValidatedBuilder::new(Team::default())
+            .constraint(|team, scope| {
+                scope.validate_string("Team must have members", |s| {
+                    s.must(team.members.clone(), NotBeEmpty)
+                })
+            })
+            .set(|team| team.members = vec!["ana".to_string()], |_, _| {})
+            .build();
+
+        expect!(result).to(be_ok());
+    }
+}
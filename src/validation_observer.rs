@@ -0,0 +1,52 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+use crate::errors::constraint_error::ConstraintError;
+use crate::errors::skipped_rule::SkippedRule;
+
+/// A trait for receiving the outcome of every rule as it is recorded.
+///
+/// Observers are registered on a `RustrictScope` through `RustrictScope::register_observer`, and
+/// are notified by each of its validation blocks right after a rule is evaluated. This makes it
+/// possible to feed pass/fail counts into a metrics system without post-processing the results.
+/// Every callback does nothing by default, so implementors only override the ones they need.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::prelude::*;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use std::sync::Arc;
+/// struct FailureCounter(AtomicUsize);
+///
+/// impl ValidationObserver for FailureCounter {
+///     fn on_failure(&self, _message: &str, _error: &ConstraintError) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let counter = Arc::new(FailureCounter(AtomicUsize::new(0)));
+/// let mut scope = RustrictScope::new();
+/// scope.register_observer(counter.clone());
+/// ```
+pub trait ValidationObserver: Send + Sync {
+    /// Called when a rule is satisfied.
+    ///
+    /// # Parameters:
+    /// - `message`: The message of the validation block the rule belongs to.
+    fn on_success(&self, _message: &str) {}
+
+    /// Called when a rule fails.
+    ///
+    /// # Parameters:
+    /// - `message`: The message of the validation block the rule belongs to.
+    /// - `error`: The error recorded for the rule.
+    fn on_failure(&self, _message: &str, _error: &ConstraintError) {}
+
+    /// Called when a rule is skipped because the deadline of its scope had passed.
+    ///
+    /// # Parameters:
+    /// - `message`: The message of the validation block the rule belongs to.
+    /// - `skipped`: The skipped rule.
+    fn on_skipped(&self, _message: &str, _skipped: &SkippedRule) {}
+}
@@ -0,0 +1,291 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! Interoperability with the `validator` crate.
+//!
+//! This module is available with the `validator-compat` feature. It converts rustrict failures
+//! into `validator::ValidationErrors` and back, so a codebase can migrate one struct at a time:
+//! structs that still derive `Validate` keep their rules, and their errors can be merged into a
+//! `RustrictScope` (or the other way around) to report everything at once.
+//!
+//! - Failures with a field (see `ConstraintError::with_field`) are keyed by that field, the
+//!   others by `__all__`, the key `validator` uses for struct-level errors.
+//! - Nested `validator` errors are flattened into paths like `address.city` and `items[0].name`.
+//!
+//! ```rust
+//! # use rustrict::prelude::*;
+//! # use validator::{ValidationError, ValidationErrors};
+//! let mut legacy = ValidationErrors::new();
+//! legacy.add("email", ValidationError::new("email"));
+//!
+//! let scope = RustrictScope::new();
+//! scope.validate_string("Roles must not be empty", |s| s.must(Vec::<&str>::new(), NotBeEmpty));
+//! scope.merge_validation_errors(&legacy);
+//!
+//! assert_eq!(scope.failure_count(), 2);
+//! assert_eq!(scope.to_validation_errors().errors().len(), 2);
+//! ```
+use crate::errors::composite_error::CompositeError;
+use crate::errors::constraint_error::ConstraintError;
+use crate::rustrict_scope::RustrictScope;
+use crate::rule_result::RuleResult;
+use std::borrow::Cow;
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+/// The key of the failures that do not belong to a field, as used by `validator`.
+pub const ALL_FIELDS: &str = "__all__";
+
+/// The code of the converted failures whose rule is unknown.
+pub const DEFAULT_CODE: &str = "rustrict";
+
+/// Converts a constraint error into a `validator::ValidationError`.
+///
+/// The code is the rule that failed (or `rustrict` if unknown), the message is the rendered
+/// message of the error, and the offending value and the hint, if any, are kept in the `value`
+/// and `hint` parameters.
+pub fn to_validation_error(error: &ConstraintError) -> ValidationError {
+    let mut converted = ValidationError::new(error.rule().unwrap_or(DEFAULT_CODE))
+        .with_message(Cow::Owned(error.message()));
+    if let Some(value) = error.value() {
+        converted.add_param(Cow::Borrowed("value"), &value);
+    }
+    if let Some(hint) = error.hint() {
+        converted.add_param(Cow::Borrowed("hint"), &hint);
+    }
+    converted
+}
+
+/// Converts constraint errors into `validator::ValidationErrors`, keyed by their field.
+///
+/// # Parameters:
+/// - `errors`: The errors to convert. Errors without a field are keyed by `__all__`.
+///
+/// # Returns:
+/// The converted errors, in the order they were given within each field.
+pub fn to_validation_errors<'a>(
+    errors: impl IntoIterator<Item = &'a ConstraintError>,
+) -> ValidationErrors {
+    let mut converted = ValidationErrors::new();
+    for error in errors {
+        let field = error.field().unwrap_or(ALL_FIELDS).to_string();
+        push(&mut converted, Cow::Owned(field), to_validation_error(error));
+    }
+    converted
+}
+
+/// Converts `validator::ValidationErrors` into constraint errors, one per `ValidationError`.
+///
+/// Nested errors are flattened into dotted paths, with list items as `[index]`, and the path is
+/// attached as the field of the error. The message is `{path}: {error}`, where `{error}` is the
+/// message of the `ValidationError`, or its code and parameters if it has none. Errors are
+/// sorted by path, since `validator` does not keep the order of its fields.
+///
+/// # Example:
+/// ```rust
+/// # use rustrict::validator_compat::from_validation_errors;
+/// # use validator::{ValidationError, ValidationErrors};
+/// let mut errors = ValidationErrors::new();
+/// errors.add("name", ValidationError::new("length").with_message("too short".into()));
+///
+/// let failures = from_validation_errors(&errors);
+/// assert_eq!(failures[0].message(), "name: too short");
+/// assert_eq!(failures[0].field(), Some("name"));
+/// ```
+pub fn from_validation_errors(errors: &ValidationErrors) -> Vec<ConstraintError> {
+    let mut flattened = Vec::new();
+    flatten(errors, "", &mut flattened);
+    flattened.sort_by(|(left, _), (right, _)| left.cmp(right));
+    flattened
+        .into_iter()
+        .map(|(path, error)| {
+            let message = format!("{}: {}", path, error);
+            ConstraintError::new(move || message.clone()).with_field(path)
+        })
+        .collect()
+}
+
+/// Collects the field errors of `errors`, prefixing their paths with `prefix`.
+fn flatten(errors: &ValidationErrors, prefix: &str, out: &mut Vec<(String, ValidationError)>) {
+    for (field, kind) in errors.errors() {
+        let path = match (prefix.is_empty(), field == ALL_FIELDS) {
+            (true, _) => field.to_string(),
+            (false, true) => prefix.to_string(),
+            (false, false) => format!("{}.{}", prefix, field),
+        };
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                out.extend(field_errors.iter().map(|error| (path.clone(), error.clone())))
+            }
+            ValidationErrorsKind::Struct(nested) => flatten(nested, &path, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    flatten(nested, &format!("{}[{}]", path, index), out)
+                }
+            }
+        }
+    }
+}
+
+/// Appends an error to the field errors of `field`.
+fn push(errors: &mut ValidationErrors, field: Cow<'static, str>, error: ValidationError) {
+    if let ValidationErrorsKind::Field(field_errors) = errors
+        .errors_mut()
+        .entry(field)
+        .or_insert_with(|| ValidationErrorsKind::Field(Vec::new()))
+    {
+        field_errors.push(error);
+    }
+}
+
+impl From<&CompositeError> for ValidationErrors {
+    /// Converts the errors of a `CompositeError`, keeping any error that is not a
+    /// `ConstraintError` under `__all__` with its `Display` output as message.
+    fn from(error: &CompositeError) -> Self {
+        let mut converted = ValidationErrors::new();
+        for error in error.errors() {
+            match error.downcast_ref::<ConstraintError>() {
+                Some(error) => {
+                    let field = error.field().unwrap_or(ALL_FIELDS).to_string();
+                    push(&mut converted, Cow::Owned(field), to_validation_error(error));
+                }
+                None => push(
+                    &mut converted,
+                    Cow::Borrowed(ALL_FIELDS),
+                    ValidationError::new(DEFAULT_CODE).with_message(Cow::Owned(error.to_string())),
+                ),
+            }
+        }
+        converted
+    }
+}
+
+impl From<CompositeError> for ValidationErrors {
+    fn from(error: CompositeError) -> Self {
+        ValidationErrors::from(&error)
+    }
+}
+
+impl RustrictScope {
+    /// Converts the failures recorded so far into `validator::ValidationErrors`.
+    ///
+    /// See `to_validation_errors` for how the failures are keyed.
+    pub fn to_validation_errors(&self) -> ValidationErrors {
+        to_validation_errors(&self.failures())
+    }
+
+    /// Records the errors reported by `validator` as failures of this scope.
+    ///
+    /// Each error becomes a failed rule in a block named after its path, so it is reported by
+    /// `into_result`, grouped by `failures_by_field` and seen by the observers, like the rules
+    /// declared in the scope itself.
+    ///
+    /// # Parameters:
+    /// - `errors`: The errors returned by a `Validate::validate` call.
+    pub fn merge_validation_errors(&self, errors: &ValidationErrors) {
+        for error in from_validation_errors(errors) {
+            let path = error.field().unwrap_or(ALL_FIELDS).to_string();
+            self.validate_string(path, |s| s.record(RuleResult::Failed(error)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constraints::be_equal_to::BeEqualTo;
+    use crate::constraints::collections::be_empty::NotBeEmpty;
+    use expectest::prelude::*;
+    use std::collections::BTreeMap;
+
+    fn messages(failures: &[ConstraintError]) -> Vec<String> {
+        failures.iter().map(ConstraintError::message).collect()
+    }
+
+    #[test]
+    fn should_key_failures_by_field() {
+        let errors = [
+            ConstraintError::new(|| "Email is invalid".to_string()).with_field("email"),
+            ConstraintError::new(|| "Dates are inverted".to_string()),
+        ];
+
+        let converted = to_validation_errors(&errors);
+
+        let fields = converted.field_errors();
+        expect!(fields["email"][0].message.as_deref()).to(be_some().value("Email is invalid"));
+        expect!(fields[ALL_FIELDS][0].code.as_ref()).to(be_equal_to(DEFAULT_CODE));
+    }
+
+    #[test]
+    fn should_keep_the_rule_and_the_value() {
+        let scope = RustrictScope::new();
+        scope.validate_string("Role", |s| s.must("guest", BeEqualTo("admin")));
+
+        let converted = scope.to_validation_errors();
+
+        let error = &converted.field_errors()[ALL_FIELDS][0];
+        expect!(error.code.contains("BeEqualTo")).to(be_true());
+        expect!(error.params.contains_key("value")).to(be_true());
+    }
+
+    #[test]
+    fn should_flatten_nested_errors_into_paths() {
+        let mut address = ValidationErrors::new();
+        address.add("city", ValidationError::new("length"));
+        let mut item = ValidationErrors::new();
+        item.add("name", ValidationError::new("required").with_message("missing".into()));
+        let mut errors = ValidationErrors::new();
+        errors.add("email", ValidationError::new("email").with_message("invalid".into()));
+        errors
+            .errors_mut()
+            .insert("address".into(), ValidationErrorsKind::Struct(Box::new(address)));
+        errors.errors_mut().insert(
+            "items".into(),
+            ValidationErrorsKind::List(BTreeMap::from([(2, Box::new(item))])),
+        );
+
+        let failures = from_validation_errors(&errors);
+
+        expect!(messages(&failures)).to(be_equal_to(vec![
+            "address.city: Validation error: length [{}]".to_string(),
+            "email: invalid".to_string(),
+            "items[2].name: missing".to_string(),
+        ]));
+        expect!(failures[2].field()).to(be_some().value("items[2].name"));
+    }
+
+    #[test]
+    fn should_merge_validator_errors_into_a_scope() {
+        let mut errors = ValidationErrors::new();
+        errors.add("email", ValidationError::new("email").with_message("invalid".into()));
+        let scope = RustrictScope::new();
+        scope.validate_string("Roles", |s| s.must(Vec::<u8>::new(), NotBeEmpty));
+
+        scope.merge_validation_errors(&errors);
+
+        expect!(scope.failure_count()).to(be_equal_to(2));
+        expect!(scope.failures_by_field().contains_key("email")).to(be_true());
+    }
+
+    #[test]
+    fn should_convert_a_composite_error() {
+        let scope = RustrictScope::new();
+        scope.validate_string("Roles", |s| s.must(Vec::<u8>::new(), NotBeEmpty));
+        let error = scope.into_result().unwrap_err();
+
+        let converted = ValidationErrors::from(error);
+
+        expect!(converted.field_errors()[ALL_FIELDS].len()).to(be_equal_to(1));
+    }
+
+    #[test]
+    fn should_round_trip_fielded_failures() {
+        let mut errors = ValidationErrors::new();
+        errors.add("name", ValidationError::new("length").with_message("too short".into()));
+
+        let converted = to_validation_errors(&from_validation_errors(&errors));
+
+        let message = converted.field_errors()["name"][0].message.clone();
+        expect!(message).to(be_some().value(Cow::from("name: too short")));
+    }
+}
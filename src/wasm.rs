@@ -0,0 +1,147 @@
+/*
+ * Copyright (c) 2024, Ignacio Slater M.
+ * 2-Clause BSD License.
+ */
+//! WebAssembly bindings for running Rustrict rules from JavaScript.
+//!
+//! This module is available with the `wasm` feature. Rules are described as JSON so that the same
+//! validation rules can be shared between a Rust backend and a web frontend:
+//!
+//! ```js
+//! import { validate } from "rustrict";
+//!
+//! const rules = JSON.stringify([
+//!     { "message": "Roles must not be empty", "constraint": "not_be_empty" },
+//!     { "message": "Roles must include viewer", "constraint": "contain_element", "element": "viewer" },
+//!     { "message": "Roles must not repeat", "constraint": "have_no_duplicates" },
+//!     { "message": "Roles must not be a single one", "constraint": "have_size", "size": 1, "negate": true },
+//! ]);
+//! const failures = validate(rules, JSON.stringify(["admin", "admin"]));
+//! // ["Roles must include viewer: missing element \"viewer\"", ...]
+//! ```
+//!
+//! Every rule is run through a `RustrictScope`, labelled with its `message`, and the messages of
+//! the failed rules are returned in order. A value that doesn't have the shape checked by a rule,
+//! like a number for `not_be_empty`, fails that rule, as it does with the `config` loader.
+use crate::rules::Rule;
+use crate::rustrict_scope::RustrictScope;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+/// Validates a JSON value against rules described in JSON, from JavaScript.
+///
+/// # Parameters:
+/// - `rules`: A JSON array of rules. Each rule has a `message`, a `constraint` name, the
+///     constraint's arguments and an optional `negate` flag.
+/// - `value`: The JSON value to validate: an array, a string or a number, depending on the rules.
+///
+/// # Returns:
+/// - `Ok(Vec<String>)`: The messages of the failed rules, received in JavaScript as an array.
+/// - `Err(JsError)`: If the rules or the value are not valid JSON, or a rule is not known.
+#[wasm_bindgen(js_name = validate)]
+pub fn validate_js(rules: &str, value: &str) -> Result<Vec<String>, JsError> {
+    validate_json(rules, value).map_err(|error| JsError::new(&error.to_string()))
+}
+
+/// Validates a JSON value against rules described in JSON.
+///
+/// This is the platform-independent core of `validate_js`.
+///
+/// # Parameters:
+/// - `rules`: A JSON array of rules.
+/// - `value`: The JSON value to validate.
+///
+/// # Returns:
+/// - `Ok(Vec<String>)`: The messages of the failed rules, in order. A rule whose value doesn't
+///   have the shape it checks, like a number for `not_be_empty`, fails with the expected shape,
+///   e.g. `Tags must not be empty: expected an array`.
+/// - `Err(serde_json::Error)`: If the rules or the value could not be parsed.
+pub fn validate_json(rules: &str, value: &str) -> Result<Vec<String>, serde_json::Error> {
+    let rules: Vec<Rule> = serde_json::from_str(rules)?;
+    let value: Value = serde_json::from_str(value)?;
+
+    let scope = RustrictScope::new();
+    for rule in rules {
+        scope.validate_string(rule.message.clone(), |s| {
+            if let Err(problem) = rule.apply(s, &value) {
+                s.constraint_with_message(|| false, || format!("{}: {}", rule.message, problem));
+            }
+        });
+    }
+    Ok(scope.failures().iter().map(|failure| failure.message()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+
+    #[test]
+    fn should_return_no_failures_for_a_valid_value() {
+        let rules = r#"[
+            { "message": "Roles must not be empty", "constraint": "not_be_empty" },
+            { "message": "Roles must include admin", "constraint": "contain_element", "element": "admin" }
+        ]"#;
+
+        let failures = validate_json(rules, r#"["admin", "viewer"]"#).unwrap();
+
+        expect!(failures.iter()).to(be_empty());
+    }
+
+    #[test]
+    fn should_return_the_messages_of_the_failed_rules_in_order() {
+        let rules = r#"[
+            { "message": "Roles must have 3 elements", "constraint": "have_size", "size": 3 },
+            { "message": "Roles must not repeat", "constraint": "have_no_duplicates" },
+            { "message": "Roles must be known", "constraint": "be_subset_of", "elements": ["admin", "viewer"] }
+        ]"#;
+
+        let failures = validate_json(rules, r#"["admin", "admin", {"role": "root"}]"#).unwrap();
+
+        expect!(failures).to(be_equal_to(vec![
+            "Roles must not repeat: element at index 1 is a duplicate".to_string(),
+            r#"Roles must be known: extraneous elements [{"role":"root"}]"#.to_string(),
+        ]));
+    }
+
+    #[test]
+    fn should_support_negated_rules() {
+        let rules = r#"[
+            { "message": "Tags must not be empty", "constraint": "be_empty", "negate": true }
+        ]"#;
+
+        let failures = validate_json(rules, "[]").unwrap();
+
+        expect!(failures.len()).to(be_equal_to(1));
+    }
+
+    #[test]
+    fn should_check_strings_and_numbers() {
+        let length = r#"[{ "message": "Name must be short", "constraint": "max_length", "length": 3 }]"#;
+        let range = r#"[{ "message": "Age must be adult", "constraint": "be_in_range", "min": 18 }]"#;
+
+        expect!(validate_json(length, r#""Ada""#).unwrap().iter()).to(be_empty());
+        expect!(validate_json(range, "17").unwrap()).to(be_equal_to(vec![
+            "Age must be adult: expected a value at least 18, got 17".to_string(),
+        ]));
+        expect!(validate_json(length, "17").unwrap()).to(be_equal_to(vec![
+            "Name must be short: expected a string".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn should_reject_unknown_constraints() {
+        let rules = r#"[{ "message": "Tags must shine", "constraint": "be_shiny" }]"#;
+
+        expect!(validate_json(rules, "[]")).to(be_err());
+    }
+
+    #[test]
+    fn should_fail_the_rules_of_values_with_another_shape() {
+        let rules = r#"[{ "message": "Tags must not be empty", "constraint": "not_be_empty" }]"#;
+
+        expect!(validate_json(rules, r#"{"tags": []}"#).unwrap()).to(be_equal_to(vec![
+            "Tags must not be empty: expected an array".to_string(),
+        ]));
+    }
+}